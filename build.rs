@@ -0,0 +1,22 @@
+//! Generowanie nagłówka C (`forest_fire.h` w katalogu `OUT_DIR`) dla interfejsu C (cecha `capi`).
+//! Nagłówek `include/forest_fire.h` w repozytorium jest aktualizowany osobnym poleceniem
+//! `cbindgen --config cbindgen.toml --output include/forest_fire.h`.
+
+fn main() {
+    #[cfg(feature = "capi")]
+    {
+        println!("cargo:rerun-if-changed=src/capi.rs");
+        println!("cargo:rerun-if-changed=cbindgen.toml");
+
+        let config = cbindgen::Config::from_file("cbindgen.toml")
+            .expect("błąd podczas wczytywania ustawień cbindgen.toml");
+        let header = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("forest_fire.h");
+
+        cbindgen::Builder::new()
+            .with_config(config)
+            .with_src("src/capi.rs")
+            .generate()
+            .expect("błąd podczas generowania nagłówka C")
+            .write_to_file(header);
+    }
+}
@@ -0,0 +1,113 @@
+#ifndef FOREST_FIRE_H
+#define FOREST_FIRE_H
+
+/* Plik wygenerowany przez cbindgen, nie należy go edytować. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Symulacja (typ nieprzezroczysty).
+ */
+typedef struct ForestFireSimulation ForestFireSimulation;
+
+/**
+ * Liczności stanów komórek po ostatnim kroku symulacji.
+ */
+typedef struct ForestFireCounts {
+  /**
+   * Liczba wykonanych kroków symulacji.
+   */
+  uint32_t steps;
+  /**
+   * Liczba żywych drzew.
+   */
+  uint64_t alive_cells;
+  /**
+   * Liczba komórek posuszu.
+   */
+  uint64_t snag_cells;
+  /**
+   * Liczba płonących komórek.
+   */
+  uint64_t on_fire_cells;
+  /**
+   * Liczba pustych komórek.
+   */
+  uint64_t dead_cells;
+  /**
+   * Łączna liczba zapłonów.
+   */
+  uint64_t ignitions;
+} ForestFireCounts;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Utworzenie symulacji na podstawie konfiguracji w formacie RON (tekst zakończony zerem,
+ * UTF-8). Zwraca `NULL` w przypadku błędu konfiguracji.
+ *
+ * # Safety
+ *
+ * `config_ron` musi wskazywać na poprawny tekst zakończony zerem.
+ */
+struct ForestFireSimulation *forest_fire_create(const char *config_ron);
+
+/**
+ * Opis ostatniego błędu w bieżącym wątku (lub `NULL`). Wskaźnik jest ważny do kolejnego
+ * błędu w tym wątku.
+ */
+const char *forest_fire_last_error(void);
+
+/**
+ * Wykonanie `steps` kroków symulacji.
+ *
+ * # Safety
+ *
+ * `simulation` musi być wskaźnikiem zwróconym przez `forest_fire_create`.
+ */
+void forest_fire_step(struct ForestFireSimulation *simulation, uint32_t steps);
+
+/**
+ * Liczności stanów komórek po ostatnim kroku symulacji (same zera dla `NULL`).
+ *
+ * # Safety
+ *
+ * `simulation` musi być wskaźnikiem zwróconym przez `forest_fire_create`.
+ */
+struct ForestFireCounts forest_fire_counts(const struct ForestFireSimulation *simulation);
+
+/**
+ * Narysowanie bieżącego stanu symulacji i zwrócenie wskaźnika na klatkę w formacie RGB
+ * (`width * height * 3` bajtów, wiersz po wierszu). Wymiary klatki są zapisywane pod `width`
+ * i `height` (jeżeli nie są `NULL`). Wskaźnik jest ważny do kolejnego wywołania
+ * `forest_fire_frame` lub `forest_fire_destroy`.
+ *
+ * # Safety
+ *
+ * `simulation` musi być wskaźnikiem zwróconym przez `forest_fire_create`, a `width`
+ * i `height` poprawnymi wskaźnikami lub `NULL`.
+ */
+const uint8_t *forest_fire_frame(struct ForestFireSimulation *simulation,
+                                 uint32_t *width,
+                                 uint32_t *height);
+
+/**
+ * Zniszczenie symulacji (`NULL` jest ignorowany).
+ *
+ * # Safety
+ *
+ * `simulation` musi być wskaźnikiem zwróconym przez `forest_fire_create`, który nie został
+ * jeszcze zniszczony.
+ */
+void forest_fire_destroy(struct ForestFireSimulation *simulation);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* FOREST_FIRE_H */
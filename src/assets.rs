@@ -0,0 +1,197 @@
+use crate::{
+    configuration::Configuration,
+    error::{ForestFireError, Operation},
+    simulation::Cell,
+};
+
+#[cfg(feature = "cli")]
+use image::imageops::FilterType;
+
+/// Raport o uszkodzeniach chronionego obiektu.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetReport {
+    /// Nazwa obiektu.
+    pub name: String,
+
+    /// Powierzchnia obiektu (w komórkach).
+    pub area: usize,
+
+    /// Krok symulacji, w którym ogień po raz pierwszy dotarł do obiektu.
+    pub first_impact_step: Option<u32>,
+
+    /// Powierzchnia obiektu objęta ogniem (w komórkach).
+    pub damaged_area: usize,
+}
+
+/// Komórka należąca do chronionego obiektu.
+struct AssetCell {
+    /// Indeks komórki na planszy z obramowaniem.
+    index: usize,
+
+    /// Indeks obiektu w konfiguracji.
+    asset: usize,
+
+    /// Czy komórka została objęta ogniem.
+    damaged: bool,
+}
+
+/// Chronione obiekty (np. zabudowania) rozmieszczone na planszy.
+pub struct Assets {
+    cells: Vec<AssetCell>,
+    pub reports: Vec<AssetReport>,
+}
+
+/// Wczytanie maski obiektu przeskalowanej do rozmiaru planszy. Zwracane są współrzędne
+/// komórek, w których maska jest jasna.
+#[cfg(feature = "cli")]
+#[inline]
+fn load_mask(
+    mask_path: &str,
+    cells_x: u32,
+    cells_y: u32,
+) -> Result<Vec<(u32, u32)>, ForestFireError> {
+    let mask = image::open(mask_path).map_err(|error| match error {
+        image::ImageError::IoError(error) => {
+            ForestFireError::io(Operation::LoadAssetMask, mask_path, error)
+        }
+        error => ForestFireError::parse(Operation::LoadAssetMask, Some(mask_path), error),
+    })?;
+
+    let mask = mask
+        .resize_exact(cells_x, cells_y, FilterType::Nearest)
+        .into_luma8();
+
+    Ok(mask
+        .enumerate_pixels()
+        .filter(|(_, _, pixel)| pixel.0[0] > 127)
+        .map(|(index_x, index_y, _)| (index_x, index_y))
+        .collect())
+}
+
+/// Maski obiektów są plikami graficznymi, a odczyt plików wymaga cechy `cli`.
+#[cfg(not(feature = "cli"))]
+#[inline]
+fn load_mask(
+    mask_path: &str,
+    _cells_x: u32,
+    _cells_y: u32,
+) -> Result<Vec<(u32, u32)>, ForestFireError> {
+    Err(ForestFireError::io(
+        Operation::LoadAssetMask,
+        mask_path,
+        std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            crate::locale::text(
+                "asset masks are not supported in this build",
+                "odczyt masek obiektów nie jest dostępny w tej wersji programu",
+            ),
+        ),
+    ))
+}
+
+impl Assets {
+    /// Wyznaczenie komórek obiektów na podstawie prostokątów i masek z konfiguracji.
+    #[inline]
+    pub fn new(config: &Configuration) -> Result<Self, ForestFireError> {
+        let cells_x = config.resolution.0 / config.cell_size;
+        let cells_y = config.resolution.1 / config.cell_size;
+
+        // Przynależność komórek do obiektów (w przypadku nakładania się decyduje ostatni obiekt)
+        let mut membership: Vec<Option<usize>> = vec![None; (cells_x * cells_y) as usize];
+
+        for (asset_index, asset) in config.assets.iter().enumerate() {
+            for &(x, y, width, height) in asset.rectangles.iter() {
+                for index_y in y..(y + height).min(cells_y) {
+                    for index_x in x..(x + width).min(cells_x) {
+                        membership[(index_y * cells_x + index_x) as usize] = Some(asset_index);
+                    }
+                }
+            }
+
+            // Maska jest skalowana do rozmiaru planszy, obiekt tworzą jasne piksele
+            if let Some(mask_path) = &asset.mask_path {
+                for (index_x, index_y) in load_mask(mask_path, cells_x, cells_y)? {
+                    membership[(index_y * cells_x + index_x) as usize] = Some(asset_index);
+                }
+            }
+        }
+
+        let cells: Vec<AssetCell> = membership
+            .iter()
+            .enumerate()
+            .filter_map(|(index, asset)| {
+                asset.map(|asset| AssetCell {
+                    index: (index / cells_x as usize + 1) * (cells_x as usize + 2)
+                        + index % cells_x as usize
+                        + 1,
+                    asset,
+                    damaged: false,
+                })
+            })
+            .collect();
+
+        let reports = config
+            .assets
+            .iter()
+            .enumerate()
+            .map(|(asset_index, asset)| AssetReport {
+                name: asset.name.clone(),
+                area: cells
+                    .iter()
+                    .filter(|cell| cell.asset == asset_index)
+                    .count(),
+                first_impact_step: None,
+                damaged_area: 0,
+            })
+            .collect();
+
+        Ok(Self { cells, reports })
+    }
+
+    /// Rejestracja uszkodzeń obiektów po wykonaniu kroku symulacji.
+    #[inline]
+    pub fn update(&mut self, state: &[Cell], step: u32) {
+        for cell in self.cells.iter_mut() {
+            if let Cell::OnFire { .. } = state[cell.index] {
+                let report = &mut self.reports[cell.asset];
+
+                if report.first_impact_step.is_none() {
+                    report.first_impact_step = Some(step);
+                }
+
+                if !cell.damaged {
+                    cell.damaged = true;
+                    report.damaged_area += 1;
+                }
+            }
+        }
+    }
+
+    /// Rysowanie obiektów do bufora klatki w kolorze obiektu (bez mieszania z kolorem
+    /// komórki, dzięki czemu paleta GIF zawiera wszystkie kolory obiektów), płonące komórki
+    /// pozostają bez zmian.
+    #[inline]
+    pub fn draw(&self, config: &Configuration, state: &[Cell], frame_buffer: &mut [u8]) {
+        let cells_x = (config.resolution.0 / config.cell_size) as usize + 2;
+        let cell_size = config.cell_size as usize;
+        let width = config.resolution.0 as usize;
+
+        for cell in self.cells.iter() {
+            if let Cell::OnFire { .. } = state[cell.index] {
+                continue;
+            }
+
+            let color = config.assets[cell.asset].color;
+            let pixel_x = (cell.index % cells_x - 1) * cell_size;
+            let pixel_y = (cell.index / cells_x - 1) * cell_size;
+
+            for offset_y in 0..cell_size {
+                let row = ((pixel_y + offset_y) * width + pixel_x) * 3;
+
+                for pixel in frame_buffer[row..row + cell_size * 3].chunks_mut(3) {
+                    pixel.copy_from_slice(&[color.0, color.1, color.2]);
+                }
+            }
+        }
+    }
+}
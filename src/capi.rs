@@ -0,0 +1,238 @@
+//! Interfejs C (ABI) do osadzania symulacji w innych programach. Nagłówek jest generowany
+//! podczas kompilacji z cechą `capi` do katalogu `OUT_DIR`, a jego kopia znajduje się
+//! w `include/forest_fire.h`.
+//!
+//! Funkcje zwracające wskaźnik zwracają `NULL` w przypadku błędu, a opis ostatniego błędu
+//! w danym wątku jest dostępny przez `forest_fire_last_error`. Panika wewnątrz biblioteki nie
+//! przechodzi przez granicę FFI i jest zgłaszana jak błąd.
+
+use crate::{
+    configuration::{parse_configuration, validate_configuration},
+    locale::text,
+    simulation::Simulation,
+};
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+thread_local! {
+    /// Opis ostatniego błędu w danym wątku.
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Symulacja (typ nieprzezroczysty).
+pub struct ForestFireSimulation {
+    simulation: Simulation,
+
+    /// Bufor klatki w formacie RGB.
+    frame_buffer: Vec<u8>,
+}
+
+/// Liczności stanów komórek po ostatnim kroku symulacji.
+#[derive(Default)]
+#[repr(C)]
+pub struct ForestFireCounts {
+    /// Liczba wykonanych kroków symulacji.
+    pub steps: u32,
+
+    /// Liczba żywych drzew.
+    pub alive_cells: u64,
+
+    /// Liczba komórek posuszu.
+    pub snag_cells: u64,
+
+    /// Liczba płonących komórek.
+    pub on_fire_cells: u64,
+
+    /// Liczba pustych komórek.
+    pub dead_cells: u64,
+
+    /// Łączna liczba zapłonów.
+    pub ignitions: u64,
+}
+
+/// Zapamiętanie opisu błędu.
+#[inline]
+fn set_last_error(error: String) {
+    let error = CString::new(error.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(error));
+}
+
+/// Opis paniki na podstawie jej zawartości.
+#[inline]
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default();
+
+    format!(
+        "{} {}",
+        text("Internal error:", "Błąd wewnętrzny:"),
+        message
+    )
+}
+
+/// Wykonanie `body` z przechwyceniem paniki, która zwraca `fallback` i zapamiętuje opis błędu.
+#[inline]
+fn guard<T>(fallback: T, body: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|payload| {
+        set_last_error(panic_message(payload));
+        fallback
+    })
+}
+
+/// Utworzenie symulacji na podstawie konfiguracji w formacie RON (tekst zakończony zerem,
+/// UTF-8). Zwraca `NULL` w przypadku błędu konfiguracji.
+///
+/// # Safety
+///
+/// `config_ron` musi wskazywać na poprawny tekst zakończony zerem.
+#[no_mangle]
+pub unsafe extern "C" fn forest_fire_create(
+    config_ron: *const c_char,
+) -> *mut ForestFireSimulation {
+    if config_ron.is_null() {
+        set_last_error(
+            text("Missing configuration (NULL)", "Brak konfiguracji (NULL)").to_string(),
+        );
+        return ptr::null_mut();
+    }
+
+    let text = match CStr::from_ptr(config_ron).to_str() {
+        Ok(text) => text,
+        Err(error) => {
+            set_last_error(error.to_string());
+            return ptr::null_mut();
+        }
+    };
+
+    guard(ptr::null_mut(), || {
+        let result = parse_configuration(text).and_then(|config| {
+            validate_configuration(&config)?;
+            Simulation::new(&config)
+        });
+
+        match result {
+            Ok(simulation) => {
+                let (width, height) = simulation.config().resolution;
+
+                Box::into_raw(Box::new(ForestFireSimulation {
+                    simulation,
+                    frame_buffer: vec![0; (width * height * 3) as usize],
+                }))
+            }
+            Err(error) => {
+                set_last_error(error.to_string());
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Opis ostatniego błędu w bieżącym wątku (lub `NULL`). Wskaźnik jest ważny do kolejnego
+/// błędu w tym wątku.
+#[no_mangle]
+pub extern "C" fn forest_fire_last_error() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or(ptr::null(), |error| error.as_ptr())
+    })
+}
+
+/// Wykonanie `steps` kroków symulacji.
+///
+/// # Safety
+///
+/// `simulation` musi być wskaźnikiem zwróconym przez `forest_fire_create`.
+#[no_mangle]
+pub unsafe extern "C" fn forest_fire_step(simulation: *mut ForestFireSimulation, steps: u32) {
+    if let Some(simulation) = simulation.as_mut() {
+        guard((), || {
+            for _ in 0..steps {
+                simulation.simulation.step();
+            }
+        });
+    }
+}
+
+/// Liczności stanów komórek po ostatnim kroku symulacji (same zera dla `NULL`).
+///
+/// # Safety
+///
+/// `simulation` musi być wskaźnikiem zwróconym przez `forest_fire_create`.
+#[no_mangle]
+pub unsafe extern "C" fn forest_fire_counts(
+    simulation: *const ForestFireSimulation,
+) -> ForestFireCounts {
+    let Some(simulation) = simulation.as_ref() else {
+        return ForestFireCounts::default();
+    };
+
+    guard(ForestFireCounts::default(), || {
+        let statistics = simulation.simulation.current_statistics();
+
+        ForestFireCounts {
+            steps: statistics.steps,
+            alive_cells: statistics.alive_cells as u64,
+            snag_cells: statistics.snag_cells as u64,
+            on_fire_cells: statistics.on_fire_cells as u64,
+            dead_cells: statistics.dead_cells as u64,
+            ignitions: statistics.ignitions,
+        }
+    })
+}
+
+/// Narysowanie bieżącego stanu symulacji i zwrócenie wskaźnika na klatkę w formacie RGB
+/// (`width * height * 3` bajtów, wiersz po wierszu). Wymiary klatki są zapisywane pod `width`
+/// i `height` (jeżeli nie są `NULL`). Wskaźnik jest ważny do kolejnego wywołania
+/// `forest_fire_frame` lub `forest_fire_destroy`.
+///
+/// # Safety
+///
+/// `simulation` musi być wskaźnikiem zwróconym przez `forest_fire_create`, a `width`
+/// i `height` poprawnymi wskaźnikami lub `NULL`.
+#[no_mangle]
+pub unsafe extern "C" fn forest_fire_frame(
+    simulation: *mut ForestFireSimulation,
+    width: *mut u32,
+    height: *mut u32,
+) -> *const u8 {
+    let Some(simulation) = simulation.as_mut() else {
+        return ptr::null();
+    };
+
+    let resolution = simulation.simulation.config().resolution;
+
+    if let Some(width) = width.as_mut() {
+        *width = resolution.0;
+    }
+
+    if let Some(height) = height.as_mut() {
+        *height = resolution.1;
+    }
+
+    guard(ptr::null(), || {
+        simulation.simulation.draw(&mut simulation.frame_buffer);
+        simulation.frame_buffer.as_ptr()
+    })
+}
+
+/// Zniszczenie symulacji (`NULL` jest ignorowany).
+///
+/// # Safety
+///
+/// `simulation` musi być wskaźnikiem zwróconym przez `forest_fire_create`, który nie został
+/// jeszcze zniszczony.
+#[no_mangle]
+pub unsafe extern "C" fn forest_fire_destroy(simulation: *mut ForestFireSimulation) {
+    if !simulation.is_null() {
+        guard((), || drop(Box::from_raw(simulation)));
+    }
+}
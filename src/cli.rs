@@ -0,0 +1,619 @@
+use clap::{Args, CommandFactory, FromArgMatches, Parser, Subcommand};
+use console::style;
+use forest_fire::error::{ForestFireError, Operation};
+use forest_fire::locale::{self, text, Language};
+use forest_fire::tr;
+
+/// Nazwy podkomend programu.
+const SUBCOMMANDS: [&str; 12] = [
+    "run",
+    "validate",
+    "print-spec",
+    "convert",
+    "stats",
+    "live",
+    "interactive",
+    "replay",
+    "serve",
+    "sweep",
+    "ensemble",
+    "help",
+];
+
+/// Forest-fire model: symulacja pożarów lasu generująca animację GIF.
+#[derive(Debug, Parser)]
+#[command(
+    version,
+    about = text(
+        "Forest-fire model: a forest fire simulation generating GIF animations",
+        "Forest-fire model: symulacja pożarów lasu generująca animację GIF",
+    )
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+
+    /// Język komunikatów (domyślnie na podstawie zmiennych `LC_ALL`, `LC_MESSAGES` i `LANG`,
+    /// a w przypadku nieobsługiwanego języka angielski).
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        value_name = "LANGUAGE",
+        help = text(
+            "Language of the messages (by default based on the LC_ALL, LC_MESSAGES and LANG \
+                variables, English for unsupported languages)",
+            "Język komunikatów (domyślnie na podstawie zmiennych LC_ALL, LC_MESSAGES i LANG, \
+                a w przypadku nieobsługiwanego języka angielski)",
+        )
+    )]
+    pub lang: Option<Language>,
+}
+
+/// Podkomendy programu.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Przeprowadzenie symulacji i zapis animacji (domyślna podkomenda).
+    #[command(about = text(
+        "Run the simulation and save the animation (default subcommand)",
+        "Przeprowadzenie symulacji i zapis animacji (domyślna podkomenda)",
+    ))]
+    Run(ConfigurationArgs),
+
+    /// Sprawdzenie poprawności pliku konfiguracyjnego.
+    #[command(about = text(
+        "Check the configuration file",
+        "Sprawdzenie poprawności pliku konfiguracyjnego",
+    ))]
+    Validate(ConfigurationArgs),
+
+    /// Wypisanie specyfikacji pliku konfiguracyjnego lub zapis jej schematu JSON Schema.
+    #[command(about = text(
+        "Print the specification of the configuration file or save its JSON Schema",
+        "Wypisanie specyfikacji pliku konfiguracyjnego lub zapis jej schematu JSON Schema",
+    ))]
+    PrintSpec(PrintSpecArgs),
+
+    /// Konwersja pliku konfiguracyjnego do innego formatu (RON, TOML, JSON lub YAML,
+    /// na podstawie rozszerzeń plików).
+    #[command(about = text(
+        "Convert a configuration file to another format (RON, TOML, JSON or YAML, \
+            based on the file extensions)",
+        "Konwersja pliku konfiguracyjnego do innego formatu (RON, TOML, JSON lub YAML, \
+            na podstawie rozszerzeń plików)",
+    ))]
+    Convert(ConvertArgs),
+
+    /// Przeprowadzenie symulacji bez zapisu animacji i wypisanie statystyk.
+    #[command(about = text(
+        "Run the simulation without saving the animation and print the statistics",
+        "Przeprowadzenie symulacji bez zapisu animacji i wypisanie statystyk",
+    ))]
+    Stats(ConfigurationArgs),
+
+    /// Podgląd symulacji na żywo w terminalu (bez zapisu animacji).
+    #[command(about = text(
+        "Live preview of the simulation in the terminal (without saving the animation)",
+        "Podgląd symulacji na żywo w terminalu (bez zapisu animacji)",
+    ))]
+    Live(ConfigurationArgs),
+
+    /// Sesja interaktywna w terminalu: pauza, praca krokowa, podpalanie i usuwanie drzew
+    /// oraz zmiana parametrów, z zapisem dziennika sesji.
+    #[command(about = text(
+        "Interactive session in the terminal: pausing, single steps, igniting and removing \
+            trees and changing parameters, with a session log",
+        "Sesja interaktywna w terminalu: pauza, praca krokowa, podpalanie i usuwanie drzew \
+            oraz zmiana parametrów, z zapisem dziennika sesji",
+    ))]
+    Interactive(InteractiveArgs),
+
+    /// Powtórzenie sesji interaktywnej na podstawie dziennika sesji.
+    #[command(about = text(
+        "Replay an interactive session from its session log",
+        "Powtórzenie sesji interaktywnej na podstawie dziennika sesji",
+    ))]
+    Replay(ReplayArgs),
+
+    /// Serwer HTTP udostępniający podgląd symulacji (strumień MJPEG) i statystyki (JSON).
+    #[command(about = text(
+        "HTTP server with a preview of the simulation (MJPEG stream) and statistics (JSON)",
+        "Serwer HTTP udostępniający podgląd symulacji (strumień MJPEG) i statystyki (JSON)",
+    ))]
+    Serve(ServeArgs),
+
+    /// Przegląd parametrów: symulacje dla wielu kombinacji parametrów z zapisem tabeli
+    /// statystyk do pliku CSV (przebieg n otrzymuje ziarno seed + n).
+    #[command(about = text(
+        "Parameter sweep: simulations for many combinations of parameters with a table \
+            of statistics saved to a CSV file (run n uses the seed seed + n)",
+        "Przegląd parametrów: symulacje dla wielu kombinacji parametrów z zapisem tabeli \
+            statystyk do pliku CSV (przebieg n otrzymuje ziarno seed + n)",
+    ))]
+    Sweep(SweepArgs),
+
+    /// Zespół symulacji Monte Carlo: wiele przebiegów z różnymi ziarnami (przebieg n otrzymuje
+    /// ziarno seed + n), mapa prawdopodobieństwa spalenia oraz średnie i wariancje statystyk.
+    #[command(about = text(
+        "Monte Carlo ensemble: many runs with different seeds (run n uses the seed seed + n), \
+            a burn probability map and the means and variances of the statistics",
+        "Zespół symulacji Monte Carlo: wiele przebiegów z różnymi ziarnami (przebieg n \
+            otrzymuje ziarno seed + n), mapa prawdopodobieństwa spalenia oraz średnie \
+            i wariancje statystyk",
+    ))]
+    Ensemble(EnsembleArgs),
+}
+
+/// Parametry wspólne dla podkomend korzystających z pliku konfiguracyjnego.
+#[derive(Debug, Args)]
+pub struct ConfigurationArgs {
+    /// Ścieżka do pliku konfiguracyjnego.
+    #[arg(help = text(
+        "Path to the configuration file (.ron, .toml, .json, .yaml or .yml)",
+        "Ścieżka do pliku konfiguracyjnego (.ron, .toml, .json, .yaml lub .yml)",
+    ))]
+    pub config_path: String,
+
+    /// Nadpisanie parametru konfiguracji, np. `--set inflammability=0.2`. Wartości podaje
+    /// się w składni JSON lub RON, pojedyncze słowa są traktowane jak napisy.
+    #[arg(
+        long = "set",
+        value_name = "PARAMETER=VALUE",
+        help = text(
+            "Override a configuration parameter, e.g. --set inflammability=0.2. Values are \
+                given in JSON or RON syntax, single words are treated as strings",
+            "Nadpisanie parametru konfiguracji, np. --set inflammability=0.2. Wartości podaje \
+                się w składni JSON lub RON, pojedyncze słowa są traktowane jak napisy",
+        )
+    )]
+    pub overrides: Vec<String>,
+
+    /// Ścieżka do pliku wynikowego (nadpisuje `output_path`).
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = text(
+            "Path to the output file (overrides output_path)",
+            "Ścieżka do pliku wynikowego (nadpisuje output_path)",
+        )
+    )]
+    pub output: Option<String>,
+
+    /// Ziarno generatora liczb losowych (nadpisuje `seed`).
+    #[arg(
+        long,
+        help = text(
+            "Seed of the random number generator (overrides seed)",
+            "Ziarno generatora liczb losowych (nadpisuje seed)",
+        )
+    )]
+    pub seed: Option<u64>,
+
+    /// Ścieżka, pod którą zostanie zapisana konfiguracja efektywna (po uwzględnieniu presetów,
+    /// wartości domyślnych i nadpisań, wraz z ziarnem i wersją programu), w formacie
+    /// ustalonym na podstawie rozszerzenia pliku.
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = text(
+            "Save the effective configuration (after presets, defaults and overrides, with \
+                the seed and the version of the program) in the format given by the extension",
+            "Zapis konfiguracji efektywnej (po uwzględnieniu presetów, wartości domyślnych \
+                i nadpisań, wraz z ziarnem i wersją programu) w formacie ustalonym na podstawie \
+                rozszerzenia pliku",
+        )
+    )]
+    pub dump_config: Option<String>,
+}
+
+impl ConfigurationArgs {
+    /// Wszystkie nadpisania parametrów, łącznie z opcjami `--output` i `--seed`.
+    #[inline]
+    pub fn all_overrides(&self) -> Vec<String> {
+        let mut overrides = self.overrides.clone();
+
+        if let Some(output) = &self.output {
+            overrides.push(format!("output_path={}", serde_json::json!(output)));
+        }
+
+        if let Some(seed) = self.seed {
+            overrides.push(format!("seed={}", seed));
+        }
+
+        overrides
+    }
+}
+
+/// Parametry podkomendy `interactive`.
+#[derive(Debug, Args)]
+pub struct InteractiveArgs {
+    #[command(flatten)]
+    pub configuration: ConfigurationArgs,
+
+    /// Ścieżka do pliku dziennika sesji (konfiguracja i zdarzenia użytkownika).
+    #[arg(
+        long,
+        value_name = "PATH",
+        default_value = "session.ron",
+        help = text(
+            "Path to the session log (configuration and user events)",
+            "Ścieżka do pliku dziennika sesji (konfiguracja i zdarzenia użytkownika)",
+        )
+    )]
+    pub log: String,
+}
+
+/// Parametry podkomendy `replay`.
+#[derive(Debug, Args)]
+pub struct ReplayArgs {
+    /// Ścieżka do pliku dziennika sesji.
+    #[arg(help = text("Path to the session log", "Ścieżka do pliku dziennika sesji"))]
+    pub log_path: String,
+
+    /// Podgląd powtórki w terminalu (zamiast samych statystyk).
+    #[arg(
+        long,
+        help = text(
+            "Watch the replay in the terminal (instead of printing the statistics only)",
+            "Podgląd powtórki w terminalu (zamiast samych statystyk)"
+        )
+    )]
+    pub watch: bool,
+}
+
+/// Parametry podkomendy `print-spec`.
+#[derive(Debug, Args)]
+pub struct PrintSpecArgs {
+    /// Ścieżka do pliku, w którym zostanie zapisany schemat JSON Schema konfiguracji
+    /// (zamiast wypisania specyfikacji).
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = text(
+            "Save the JSON Schema of the configuration to the file (instead of printing \
+                the specification)",
+            "Zapis schematu JSON Schema konfiguracji do pliku (zamiast wypisania \
+                specyfikacji)",
+        )
+    )]
+    pub schema: Option<String>,
+}
+
+/// Parametry podkomendy `convert`.
+#[derive(Debug, Args)]
+pub struct ConvertArgs {
+    /// Ścieżka do konwertowanego pliku konfiguracyjnego.
+    #[arg(help = text(
+        "Path to the converted configuration file",
+        "Ścieżka do konwertowanego pliku konfiguracyjnego",
+    ))]
+    pub input_path: String,
+
+    /// Ścieżka do pliku wynikowego (.ron, .toml, .json, .yaml lub .yml).
+    #[arg(help = text(
+        "Path to the output file (.ron, .toml, .json, .yaml or .yml)",
+        "Ścieżka do pliku wynikowego (.ron, .toml, .json, .yaml lub .yml)",
+    ))]
+    pub output_path: String,
+}
+
+/// Parametry podkomendy `serve`.
+#[derive(Debug, Args)]
+pub struct ServeArgs {
+    #[command(flatten)]
+    pub configuration: ConfigurationArgs,
+
+    /// Adres, na którym nasłuchuje serwer.
+    #[arg(
+        long,
+        value_name = "ADDRESS",
+        default_value = "127.0.0.1:8080",
+        help = text("Address the server listens on", "Adres, na którym nasłuchuje serwer")
+    )]
+    pub address: String,
+}
+
+/// Parametry podkomendy `sweep`.
+#[derive(Debug, Args)]
+pub struct SweepArgs {
+    /// Bazowa konfiguracja przeglądu. Ziarno `seed` jest ziarnem bazowym: przebieg `n`
+    /// otrzymuje ziarno `seed + n`.
+    #[command(flatten)]
+    pub configuration: ConfigurationArgs,
+
+    /// Przeszukiwany parametr w postaci `parametr=początek:koniec:liczba` (zakres) lub
+    /// `parametr=[wartość, ...]` (lista w składni JSON), np. `--param inflammability=0.05:0.3:6`.
+    #[arg(
+        long = "param",
+        value_name = "PARAMETER=VALUES",
+        required = true,
+        help = text(
+            "Swept parameter in the form parameter=start:end:count (range) or \
+                parameter=[value, ...] (list in JSON syntax), e.g. \
+                --param inflammability=0.05:0.3:6",
+            "Przeszukiwany parametr w postaci parametr=początek:koniec:liczba (zakres) lub \
+                parametr=[wartość, ...] (lista w składni JSON), np. \
+                --param inflammability=0.05:0.3:6",
+        )
+    )]
+    pub parameters: Vec<String>,
+
+    /// Liczba próbek łacińskiego hipersześcianu (zamiast iloczynu kartezjańskiego).
+    #[arg(
+        long,
+        help = text(
+            "Number of Latin hypercube samples (instead of the Cartesian product)",
+            "Liczba próbek łacińskiego hipersześcianu (zamiast iloczynu kartezjańskiego)",
+        )
+    )]
+    pub samples: Option<usize>,
+
+    /// Liczba równoległych wątków (domyślnie liczba dostępnych procesorów).
+    #[arg(long, help = jobs_help())]
+    pub jobs: Option<usize>,
+
+    /// Ścieżka do pliku z tabelą podsumowującą.
+    #[arg(
+        long,
+        value_name = "PATH",
+        default_value = "sweep.csv",
+        help = text(
+            "Path to the summary table",
+            "Ścieżka do pliku z tabelą podsumowującą",
+        )
+    )]
+    pub summary: String,
+}
+
+/// Parametry podkomendy `ensemble`.
+#[derive(Debug, Args)]
+pub struct EnsembleArgs {
+    /// Konfiguracja zespołu. Ziarno `seed` jest ziarnem bazowym: przebieg `n` otrzymuje
+    /// ziarno `seed + n`.
+    #[command(flatten)]
+    pub configuration: ConfigurationArgs,
+
+    /// Liczba przebiegów zespołu.
+    #[arg(
+        long,
+        default_value_t = 100,
+        help = text("Number of runs of the ensemble", "Liczba przebiegów zespołu")
+    )]
+    pub replicas: usize,
+
+    /// Liczba równoległych wątków (domyślnie liczba dostępnych procesorów).
+    #[arg(long, help = jobs_help())]
+    pub jobs: Option<usize>,
+
+    /// Ścieżka do obrazu PNG z mapą prawdopodobieństwa spalenia.
+    #[arg(
+        long,
+        value_name = "PATH",
+        default_value = "burn_probability.png",
+        help = text(
+            "Path to the PNG image with the burn probability map",
+            "Ścieżka do obrazu PNG z mapą prawdopodobieństwa spalenia",
+        )
+    )]
+    pub heatmap: String,
+
+    /// Ścieżka do pliku CSV z siatką prawdopodobieństwa spalenia (wiersze planszy).
+    #[arg(
+        long,
+        value_name = "PATH",
+        default_value = "burn_probability.csv",
+        help = text(
+            "Path to the CSV file with the burn probability grid (rows of the grid)",
+            "Ścieżka do pliku CSV z siatką prawdopodobieństwa spalenia (wiersze planszy)",
+        )
+    )]
+    pub grid: String,
+
+    /// Ścieżka do pliku CSV ze średnimi i wariancjami statystyk zbiorczych.
+    #[arg(
+        long,
+        value_name = "PATH",
+        default_value = "ensemble_summary.csv",
+        help = text(
+            "Path to the CSV file with the mean and variance of the final statistics",
+            "Ścieżka do pliku CSV ze średnimi i wariancjami statystyk zbiorczych",
+        )
+    )]
+    pub summary: String,
+}
+
+/// Opis opcji `--jobs` (wspólny dla podkomend `sweep` i `ensemble`).
+#[inline]
+fn jobs_help() -> &'static str {
+    text(
+        "Number of parallel threads (by default the number of available processors)",
+        "Liczba równoległych wątków (domyślnie liczba dostępnych procesorów)",
+    )
+}
+
+/// Funkcja parsująca parametry wywołania programu. Dla zgodności z wcześniejszym sposobem
+/// wywołania (`forest_fire config.ron`) brak podkomendy oznacza podkomendę `run`.
+#[inline]
+pub fn parse_arguments() -> Cli {
+    let mut arguments: Vec<String> = std::env::args().collect();
+
+    // Opcje globalne (wraz z wartościami) mogą poprzedzać podkomendę
+    let mut index = 1;
+
+    while let Some(argument) = arguments.get(index) {
+        index += match argument.as_str() {
+            "--lang" => 2,
+            argument if argument.starts_with("--lang=") => 1,
+            _ => break,
+        };
+    }
+
+    if let Some(first) = arguments.get(index) {
+        if !first.starts_with('-') && !SUBCOMMANDS.contains(&first.as_str()) {
+            arguments.insert(index, "run".to_string());
+        }
+    }
+
+    // Opisy w pomocy programu są tworzone w języku wybranym przed parsowaniem parametrów
+    locale::set_language(requested_language(&arguments).unwrap_or_else(Language::detect));
+
+    let mut command = Cli::command();
+    command.build();
+    localize_builtins(&mut command);
+
+    command
+        .try_get_matches_from(arguments)
+        .and_then(|matches| Cli::from_arg_matches(&matches))
+        .unwrap_or_else(|error| error.exit())
+}
+
+/// Opisy opcji i podkomend dodawanych przez bibliotekę clap (`--help`, `--version`
+/// i `help`) w aktualnym języku.
+fn localize_builtins(command: &mut clap::Command) {
+    let mut localized = std::mem::take(command);
+
+    if localized
+        .get_arguments()
+        .any(|argument| argument.get_id() == "help")
+    {
+        localized = localized.mut_arg("help", |argument| {
+            let long_help = argument.get_long_help().map(|_| {
+                text(
+                    "Print help (see a summary with '-h')",
+                    "Wypisanie pomocy (skróconej dla '-h')",
+                )
+            });
+
+            argument
+                .help(text("Print help", "Wypisanie pomocy"))
+                .long_help(long_help)
+        });
+    }
+
+    if localized
+        .get_arguments()
+        .any(|argument| argument.get_id() == "version")
+    {
+        localized = localized.mut_arg("version", |argument| {
+            argument.help(text("Print version", "Wypisanie wersji"))
+        });
+    }
+
+    if localized.find_subcommand("help").is_some() {
+        localized = localized.mut_subcommand("help", |subcommand| {
+            subcommand.about(text(
+                "Print this message or the help of the given subcommand(s)",
+                "Wypisanie tej pomocy lub pomocy podanych podkomend",
+            ))
+        });
+    }
+
+    localized.get_subcommands_mut().for_each(localize_builtins);
+
+    *command = localized;
+}
+
+/// Język podany w opcji `--lang` (w dowolnym miejscu wywołania, przed argumentem `--`).
+#[inline]
+fn requested_language(arguments: &[String]) -> Option<Language> {
+    let arguments = &arguments[..arguments
+        .iter()
+        .position(|argument| argument == "--")
+        .unwrap_or(arguments.len())];
+
+    arguments
+        .iter()
+        .enumerate()
+        .filter_map(|(index, argument)| match argument.strip_prefix("--lang") {
+            Some("") => arguments.get(index + 1).map(String::as_str),
+            Some(value) => value.strip_prefix('='),
+            None => None,
+        })
+        .next_back()
+        .and_then(Language::from_code)
+}
+
+/// Sformatowanie opisu błędu do wypisania w terminalu (z kolorowaniem).
+#[inline]
+pub fn render_error(error: &ForestFireError) -> String {
+    let title = |operation: &Operation| {
+        style(tr!("Error while {}!", "Błąd podczas {}!", operation))
+            .red()
+            .bold()
+            .to_string()
+    };
+    let location = |path: &str| style(format!("\"{}\"", path)).cyan().italic().bold();
+
+    let (header, details) = match error {
+        ForestFireError::Io {
+            operation,
+            path,
+            source,
+        } => (
+            format!("{} ({})", title(operation), location(path)),
+            source.to_string(),
+        ),
+        ForestFireError::Parse {
+            operation,
+            path,
+            line,
+            column,
+            message,
+        } => {
+            let mut header = title(operation);
+
+            if let Some(path) = path {
+                header = format!("{} ({})", header, location(path));
+            }
+
+            let details = match (line, column) {
+                (Some(line), Some(column)) => {
+                    tr!(
+                        "Line {}, column {}: {}",
+                        "Linia {}, kolumna {}: {}",
+                        line,
+                        column,
+                        message
+                    )
+                }
+                _ => message.clone(),
+            };
+
+            (header, details)
+        }
+        ForestFireError::Validation { violations } => (
+            style(text("Configuration error!", "Błąd konfiguracji!"))
+                .red()
+                .bold()
+                .to_string(),
+            violations
+                .iter()
+                .map(|violation| {
+                    tr!(
+                        "Parameter {} must be {}, got: {}",
+                        "Parametr {} musi przyjmować {}, podano: {}",
+                        style(&violation.field).yellow().bold(),
+                        violation.constraint,
+                        violation.value
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
+        ),
+        ForestFireError::Encoding {
+            operation,
+            path,
+            message,
+        } => (
+            format!("{} ({})", title(operation), location(path)),
+            message.clone(),
+        ),
+    };
+
+    format!(
+        "{}\n\n{}\n    {}\n",
+        header,
+        text("Details:", "Szczegóły:"),
+        details.replace('\n', "\n    ")
+    )
+}
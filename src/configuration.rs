@@ -1,74 +1,518 @@
+use crate::{
+    error::{ForestFireError, Operation, Violation},
+    locale::text,
+    presets,
+    specification::{self, Bounds, ParameterDefinition, Schema},
+    tr,
+};
+
+#[cfg(feature = "cli")]
+use crate::specification::Parameter;
+#[cfg(feature = "cli")]
 use console::style;
-use ron::de::from_reader;
-use serde::Deserialize;
+#[cfg(feature = "cli")]
+use ron::extensions::Extensions;
+use serde::{Deserialize, Deserializer, Serialize};
 
-use std::fs::File;
-use std::io::BufReader;
 use std::path::Path;
 
-/// Struktura reprezentująca konfigurację symulacji.
-#[derive(Debug, Deserialize, Clone)]
-pub struct Configuration {
-    /// Liczba klatek symulacji.
-    pub frames: u32,
+/// Strategia działania jednostek straży pożarnej.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum FirefightingStrategy {
+    /// Jednostka kieruje się do najbliższego płonącego drzewa.
+    Nearest,
+
+    /// Jednostka kieruje się do najbliższego płonącego drzewa na froncie pożaru
+    /// (sąsiadującego z żywym lasem).
+    Perimeter,
+
+    /// Jednostka broni obszaru wokół zadanej komórki (x, y), gasząc płonące drzewa
+    /// położone najbliżej tego punktu.
+    ProtectArea { x: u32, y: u32 },
+}
+
+/// Rodzaj zabiegu wykonywanego w ramach zarządzania lasem.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum ManagementAction {
+    /// Wypalanie kontrolowane, podpala posusz oraz młode drzewa (ogień może rozprzestrzenić
+    /// się poza wypalany fragment lasu).
+    ControlledBurn,
+
+    /// Wycinka, usuwa wszystkie drzewa.
+    ClearCut,
+}
+
+/// Strategia wyboru fragmentów lasu poddawanych zabiegom.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum ManagementStrategy {
+    /// Losowo wybrane fragmenty.
+    RandomPatches,
+
+    /// Kolejne fragmenty regularnej siatki, cyklicznie.
+    GridPattern,
+
+    /// Fragmenty o największym średnim wieku drzew.
+    OldestFirst,
+}
+
+/// Format pliku wynikowego.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    /// Animacja GIF (paleta 256 kolorów).
+    Gif,
+
+    /// Animacja APNG (bezstratna, pełna paleta kolorów). Plik APNG jest poprawnym plikiem
+    /// PNG, dlatego programy bez obsługi animacji wyświetlają jego pierwszą klatkę.
+    Apng,
+
+    /// Animacja WebP (bezstratna, pełna paleta kolorów).
+    WebP,
+
+    /// Sekwencja ponumerowanych klatek PNG zapisywanych w katalogu `output_path`.
+    PngSequence,
+
+    /// Nieskompresowany strumień wideo YUV4MPEG2 (.y4m).
+    Y4m,
+
+    /// Klatki RGB przekazywane na standardowe wejście zewnętrznego kodera
+    /// (polecenie `output_command`).
+    Pipe,
+}
+
+/// Chroniony obiekt (np. zabudowania), którego uszkodzenia są raportowane po symulacji.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Asset {
+    /// Nazwa obiektu.
+    pub name: String,
+
+    /// Kolor obiektu.
+    pub color: (u8, u8, u8),
+
+    /// Obszary obiektu w postaci prostokątów (x, y, szerokość, wysokość) wyrażonych w komórkach.
+    #[serde(default)]
+    pub rectangles: Vec<(u32, u32, u32, u32)>,
+
+    /// Ścieżka do obrazu maski obiektu (jasne piksele należą do obiektu). Obraz jest
+    /// skalowany do rozmiaru planszy.
+    #[serde(default)]
+    pub mask_path: Option<String>,
+}
+
+/// Deserializacja parametru obecnego w pliku (pominięte parametry mają wartość `None`).
+#[inline]
+fn present<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+/// Definicja struktury konfiguracji. Każdy parametr jest opisany dopuszczalnymi zakresami
+/// wartości i opisem w obu językach (`parametr: typ => [zakresy], ("opis", "opis")`), na
+/// podstawie których, wraz z typem parametru, generowana jest specyfikacja konfiguracji
+/// (`PARAMETERS`). Makro definiuje również warstwę konfiguracji (`ConfigurationLayer`)
+/// używaną przy dziedziczeniu parametrów.
+macro_rules! configuration {
+    (
+        $(#[$attribute:meta])*
+        pub struct Configuration {
+            $(
+                $(#[$field_attribute:meta])*
+                pub $name:ident: $type:ty => [$($bounds:expr),*],
+                    ($english:literal, $polish:literal $(,)?),
+            )*
+        }
+    ) => {
+        $(#[$attribute])*
+        pub struct Configuration {
+            $(
+                $(#[$field_attribute])*
+                pub $name: $type,
+            )*
+        }
+
+        /// Definicje parametrów konfiguracji (w kolejności pól struktury `Configuration`).
+        pub const PARAMETERS: &[ParameterDefinition] = &[
+            $(
+                ParameterDefinition {
+                    name: stringify!($name),
+                    type_name: stringify!($type),
+                    schema: <$type as Schema>::schema,
+                    bounds: &[$($bounds),*],
+                    description: ($english, $polish),
+                },
+            )*
+        ];
+
+        /// Pojedyncza warstwa konfiguracji (plik lub preset) przed połączeniem z przodkami:
+        /// wszystkie parametry są opcjonalne, dzięki czemu wiadomo, które z nich podano jawnie.
+        #[derive(Debug, Default, Deserialize)]
+        #[serde(rename = "Configuration", default)]
+        pub(crate) struct ConfigurationLayer {
+            /// Preset lub plik, z którego dziedziczona jest konfiguracja.
+            pub(crate) extends: Option<String>,
+
+            $(
+                #[serde(deserialize_with = "present")]
+                $name: Option<$type>,
+            )*
+        }
+
+        impl ConfigurationLayer {
+            /// Parametry podane jawnie w warstwie.
+            #[inline]
+            pub(crate) fn into_fields(self) -> serde_json::Map<String, serde_json::Value> {
+                let mut fields = serde_json::Map::new();
+
+                $(
+                    if let Some(value) = self.$name {
+                        fields.insert(
+                            stringify!($name).to_string(),
+                            serde_json::to_value(value).unwrap(),
+                        );
+                    }
+                )*
+
+                fields
+            }
+        }
+    };
+}
+
+configuration! {
+    /// Struktura reprezentująca konfigurację symulacji.
+    #[derive(Debug, Serialize, Deserialize, Clone)]
+    pub struct Configuration {
+        /// Wersja programu, który zapisał konfigurację efektywną (informacyjnie, pomijana
+        /// w konfiguracjach pisanych ręcznie). Parametr opcjonalny.
+        #[serde(
+            default = "Configuration::default_version",
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub version: Option<String> => [],
+            (
+                "Version of the program that wrote the configuration",
+                "Wersja programu, który zapisał konfigurację",
+            ),
+
+        /// Liczba klatek symulacji.
+        pub frames: u32 => [Bounds::at_least(1.0)],
+            ("Number of simulation frames", "Liczba klatek symulacji"),
+
+        /// Liczba klatek na sekundę animacji (dla animacji GIF co najwyżej
+        /// `GIF_MAX_FRAME_RATE`).
+        pub frame_rate: u32 => [Bounds::between(1.0, 100.0)],
+            (
+                "Number of animation frames per second (at most 50 for GIF animations)",
+                "Liczba klatek na sekundę animacji (dla animacji GIF co najwyżej 50)",
+            ),
+
+        /// Liczba kroków symulacji przypadających na jedną klatkę animacji (rysowany jest co
+        /// n-ty krok). Parametr opcjonalny.
+        #[serde(default = "Configuration::default_steps_per_frame")]
+        pub steps_per_frame: u32 => [Bounds::at_least(1.0)],
+            ("Number of simulation steps per frame", "Liczba kroków symulacji na klatkę"),
+
+        /// Liczba kroków symulacji wykonywanych przed rozpoczęciem zapisu animacji (dochodzenie
+        /// do stanu ustalonego). Parametr opcjonalny.
+        #[serde(default = "Configuration::default_warmup_steps")]
+        pub warmup_steps: u32 => [],
+            ("Number of steps before recording starts", "Liczba kroków przed rozpoczęciem zapisu"),
+
+        /// Ścieżka do pliku wynikowego. Format pliku jest ustalany na podstawie rozszerzenia
+        /// (.gif, .png lub .apng, .webp, .y4m, .mp4/.webm/.mkv/.mov przez zewnętrzny koder;
+        /// brak rozszerzenia oznacza katalog z sekwencją klatek PNG), o ile nie podano
+        /// parametru `output_format`.
+        pub output_path: String => [], ("Path to the output file", "Ścieżka do pliku wynikowego"),
+
+        /// Format pliku wynikowego. Brak wartości oznacza format ustalany na podstawie
+        /// rozszerzenia pliku wynikowego. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_output_format")]
+        pub output_format: Option<OutputFormat> => [],
+            (
+                "Output file format (Gif, Apng, WebP, PngSequence, Y4m, Pipe)",
+                "Format pliku wynikowego (Gif, Apng, WebP, PngSequence, Y4m, Pipe)",
+            ),
+
+        /// Polecenie zewnętrznego kodera (program i argumenty) dla formatu `Pipe`. W argumentach
+        /// można użyć symboli {width}, {height}, {frame_rate} i {output}. Pusta lista oznacza
+        /// domyślne polecenie ffmpeg. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_output_command")]
+        pub output_command: Vec<String> => [],
+            ("External encoder command", "Polecenie zewnętrznego kodera"),
+
+        /// Jakość kwantyzacji kolorów animacji GIF (1..100), określa liczbę odcieni każdego
+        /// koloru w palecie globalnej. Niższa jakość oznacza mniejszy plik. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_gif_quality")]
+        pub gif_quality: u32 => [Bounds::between(1.0, 100.0)],
+            ("GIF colour quantization quality", "Jakość kwantyzacji kolorów GIF"),
+
+        /// Rozdzielczość generowanej animacji: (pozioma, pionowa).
+        pub resolution: (u32, u32) => [Bounds::between(256.0, 4096.0), Bounds::between(256.0, 2160.0)],
+            ("Resolution", "Rozdzielczość"),
+
+        /// Wielkość pojedynczej komórki w pikselach. Ten parametr musi być tak
+        /// dobrany aby dzielił wartość rozdzielczości poziomej i pionowej bez reszty.
+        pub cell_size: u32 => [Bounds::at_least(1.0)],
+            ("Cell size (a divisor of the resolution)", "Wielkość komórki (dzielnik rozdzielczości)"),
+
+        /// Ziarno generatora liczb losowych. Brak wartości oznacza losowe ziarno.
+        /// Parametr opcjonalny.
+        #[serde(default = "Configuration::default_seed")]
+        pub seed: Option<u64> => [],
+            ("Random number generator seed", "Ziarno generatora liczb losowych"),
+
+        /// Frakcja komórek żywych na początku symulacji. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_alive_fraction")]
+        pub alive_fraction: f32 => [Bounds::between(0.0, 1.0)],
+            ("Fraction of cells occupied by trees", "Frakcja komórek zajętych przez drzewa"),
+
+        /// Prawdopodobieństwo wykiełkowania (przy kontakcie z innymi drzewami). Parametr opcjonalny.
+        #[serde(default = "Configuration::default_sprout_probability")]
+        pub sprout_probability: f32 => [Bounds::between(0.0, 1.0)],
+            ("Sprouting probability", "Prawdopodobieństwo wykiełkowania"),
+
+        /// Prawdopodobieństwo losowego wykiełkowania. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_random_sprout_probability")]
+        pub random_sprout_probability: f32 => [Bounds::between(0.0, 1.0)],
+            ("Random sprouting probability", "Prawdopodobieństwo losowego wykiełkowania"),
+
+        /// Szybkość wzrostu drzew. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_growth_rate")]
+        pub growth_rate: f32 => [Bounds::at_least(0.0)], ("Growth rate", "Tempo wzrostu"),
+
+        /// Łatwopalność drzew, określa jak łatwo drzewa zajmują się ogniem od
+        /// innych drzew. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_inflammability")]
+        pub inflammability: f32 => [Bounds::between(0.0, 1.0)], ("Inflammability", "Łatwopalność"),
+
+        /// Prawdopodobieństwo samozapłonu. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_self_ignition_probability")]
+        pub self_ignition_probability: f32 => [Bounds::between(0.0, 1.0)],
+            ("Self-ignition probability", "Prawdopodobieństwo samozapłonu"),
+
+        /// Szybkość spalania. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_burning_rate")]
+        pub burning_rate: f32 => [Bounds::greater_than(0.0)], ("Burning rate", "Szybkość spalania"),
+
+        /// Wiek drzewa (w krokach symulacji), dla którego prawdopodobieństwo śmierci
+        /// naturalnej osiąga wartość `mortality_probability`. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_mortality_age")]
+        pub mortality_age: u32 => [Bounds::at_least(1.0)],
+            ("Reference age of tree mortality", "Wiek referencyjny śmiertelności drzew"),
+
+        /// Prawdopodobieństwo śmierci naturalnej drzewa w wieku `mortality_age`. Śmiertelność
+        /// zależy wyłącznie od wieku drzewa (a nie od jego rozmiaru). Wartość 0.0 wyłącza
+        /// śmiertelność. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_mortality_probability")]
+        pub mortality_probability: f32 => [Bounds::between(0.0, 1.0)],
+            ("Natural death probability", "Prawdopodobieństwo śmierci naturalnej"),
+
+        /// Wykładnik krzywej śmiertelności, prawdopodobieństwo śmierci wynosi
+        /// `mortality_probability * (wiek / mortality_age) ^ mortality_exponent`. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_mortality_exponent")]
+        pub mortality_exponent: f32 => [Bounds::at_least(0.0)],
+            ("Mortality curve exponent", "Wykładnik krzywej śmiertelności"),
 
-    /// Liczba klatek na sekundę animacji.
-    pub frame_rate: u32,
+        /// Łatwopalność posuszu (martwych, stojących drzew). Parametr opcjonalny.
+        #[serde(default = "Configuration::default_snag_inflammability")]
+        pub snag_inflammability: f32 => [Bounds::between(0.0, 1.0)],
+            ("Snag inflammability", "Łatwopalność posuszu"),
 
-    /// Ścieżka do pliku wynikowego. Formatem wynikowym jest GIF więc
-    /// nazwa pliku powinna mieć rozszerzenie .gif.
-    pub output_path: String,
+        /// Szybkość rozpadu posuszu. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_snag_decay_rate")]
+        pub snag_decay_rate: f32 => [Bounds::greater_than(0.0)],
+            ("Snag decay rate", "Szybkość rozpadu posuszu"),
 
-    /// Rozdzielczość generowanej animacji: (pozioma, pionowa).
-    pub resolution: (u32, u32),
+        /// Liczba jednostek straży pożarnej. Wartość 0 wyłącza gaszenie pożarów.
+        /// Parametr opcjonalny.
+        #[serde(default = "Configuration::default_firefighter_count")]
+        pub firefighter_count: u32 => [],
+            ("Number of firefighter units", "Liczba jednostek straży pożarnej"),
 
-    /// Wielkość pojedynczej komórki w pikselach. Ten parametr musi być tak
-    /// dobrany aby dzielił wartość rozdzielczości poziomej i pionowej bez reszty.
-    pub cell_size: u32,
+        /// Strategia działania jednostek straży pożarnej. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_firefighter_strategy")]
+        pub firefighter_strategy: FirefightingStrategy => [],
+            (
+                "Firefighting strategy (Nearest, Perimeter, ProtectArea(x, y))",
+                "Strategia gaszenia (Nearest, Perimeter, ProtectArea(x, y))",
+            ),
 
-    /// Frakcja komórek żywych na początku symulacji. Parametr opcjonalny.
-    #[serde(default = "Configuration::default_alive_fraction")]
-    pub alive_fraction: f32,
+        /// Liczba komórek, o którą jednostka może przesunąć się w jednym kroku symulacji.
+        /// Parametr opcjonalny.
+        #[serde(default = "Configuration::default_firefighter_speed")]
+        pub firefighter_speed: u32 => [Bounds::at_least(1.0)],
+            ("Unit speed (cells per step)", "Prędkość jednostek (komórki na krok)"),
 
-    /// Prawdopodobieństwo wykiełkowania (przy kontakcie z innymi drzewami). Parametr opcjonalny.
-    #[serde(default = "Configuration::default_sprout_probability")]
-    pub sprout_probability: f32,
+        /// Promień (w komórkach), w którym jednostka gasi płonące drzewa i zwilża pozostałe
+        /// drzewa. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_firefighter_radius")]
+        pub firefighter_radius: u32 => [],
+            ("Extinguishing radius (in cells)", "Promień gaszenia (w komórkach)"),
 
-    /// Prawdopodobieństwo losowego wykiełkowania. Parametr opcjonalny.
-    #[serde(default = "Configuration::default_random_sprout_probability")]
-    pub random_sprout_probability: f32,
+        /// Liczba kroków symulacji, przez które drzewa zwilżone przez jednostkę nie mogą się
+        /// zapalić. Wartość 0 wyłącza zwilżanie. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_firefighter_wetting_steps")]
+        pub firefighter_wetting_steps: u32 => [],
+            (
+                "Steps for which wetted trees cannot ignite (0 disables)",
+                "Liczba kroków, przez które zwilżone drzewa nie zapalają się (0 wyłącza)",
+            ),
 
-    /// Szybkość wzrostu drzew. Parametr opcjonalny.
-    #[serde(default = "Configuration::default_growth_rate")]
-    pub growth_rate: f32,
+        /// Lista chronionych obiektów. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_assets")]
+        pub assets: Vec<Asset> => [], ("Protected assets", "Chronione obiekty"),
 
-    /// Łatwopalność drzew, określa jak łatwo drzewa zajmują się ogniem od
-    /// innych drzew. Parametr opcjonalny.
-    #[serde(default = "Configuration::default_inflammability")]
-    pub inflammability: f32,
+        /// Odstęp (w krokach symulacji) między zabiegami zarządzania lasem. Wartość 0
+        /// wyłącza zarządzanie. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_management_interval")]
+        pub management_interval: u32 => [],
+            ("Interval between treatments (0 disables)", "Odstęp między zabiegami (0 wyłącza)"),
 
-    /// Prawdopodobieństwo samozapłonu. Parametr opcjonalny.
-    #[serde(default = "Configuration::default_self_ignition_probability")]
-    pub self_ignition_probability: f32,
+        /// Rodzaj zabiegu. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_management_action")]
+        pub management_action: ManagementAction => [],
+            ("Treatment (ControlledBurn, ClearCut)", "Zabieg (ControlledBurn, ClearCut)"),
 
-    /// Szybkość spalania. Parametr opcjonalny.
-    #[serde(default = "Configuration::default_burning_rate")]
-    pub burning_rate: f32,
+        /// Strategia wyboru fragmentów lasu poddawanych zabiegom. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_management_strategy")]
+        pub management_strategy: ManagementStrategy => [],
+            (
+                "Strategy (RandomPatches, GridPattern, OldestFirst)",
+                "Strategia (RandomPatches, GridPattern, OldestFirst)",
+            ),
 
-    /// Paleta kolorystyczna lasu. Parametr opcjonalny.
-    #[serde(default = "Configuration::default_forest_color_palette")]
-    pub forest_color_palette: Vec<(u8, u8, u8)>,
+        /// Frakcja fragmentów lasu poddawanych pojedynczemu zabiegowi. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_management_fraction")]
+        pub management_fraction: f32 => [Bounds::between(0.0, 1.0)],
+            ("Fraction of forest patches per treatment", "Frakcja fragmentów lasu na zabieg"),
 
-    /// Kolor płomienia. Parametr opcjonalny.
-    #[serde(default = "Configuration::default_fire_color")]
-    pub fire_color: (u8, u8, u8),
+        /// Bok kwadratowego fragmentu lasu (w komórkach). Parametr opcjonalny.
+        #[serde(default = "Configuration::default_management_patch_size")]
+        pub management_patch_size: u32 => [Bounds::at_least(1.0)],
+            ("Forest patch side (in cells)", "Bok fragmentu lasu (w komórkach)"),
 
-    /// Kolor podłoża. Parametr opcjonalny.
-    #[serde(default = "Configuration::default_ground_color")]
-    pub ground_color: (u8, u8, u8),
+        /// Obszary zarządzane w postaci prostokątów (x, y, szerokość, wysokość) wyrażonych
+        /// w komórkach. Pusta lista oznacza całą planszę. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_management_regions")]
+        pub management_regions: Vec<(u32, u32, u32, u32)> => [],
+            ("Managed regions", "Obszary zarządzane"),
+
+        /// Czy przeprowadzić dodatkową symulację bez zarządzania w celu porównania
+        /// statystyk pożarów. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_management_baseline")]
+        pub management_baseline: bool => [],
+            ("Comparison simulation without management", "Symulacja porównawcza bez zarządzania"),
+
+        /// Paleta kolorystyczna lasu. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_forest_color_palette")]
+        pub forest_color_palette: Vec<(u8, u8, u8)> => [],
+            ("Forest colour palette (non-empty)", "Paleta kolorystyczna lasu (niepusta)"),
+
+        /// Kolor płomienia. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_fire_color")]
+        pub fire_color: (u8, u8, u8) => [], ("Fire colour", "Kolor ognia"),
+
+        /// Kolor podłoża. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_ground_color")]
+        pub ground_color: (u8, u8, u8) => [], ("Ground colour", "Kolor podłoża"),
+
+        /// Kolor posuszu. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_snag_color")]
+        pub snag_color: (u8, u8, u8) => [], ("Snag colour", "Kolor posuszu"),
+
+        /// Kolor znaczników jednostek straży pożarnej. Parametr opcjonalny.
+        #[serde(default = "Configuration::default_firefighter_color")]
+        pub firefighter_color: (u8, u8, u8) => [],
+            ("Firefighter unit colour", "Kolor jednostek straży pożarnej"),
+    }
 }
 
 impl Configuration {
+    /// Łączna liczba kroków symulacji: kroki rozgrzewki oraz kroki wszystkich klatek animacji.
+    #[inline]
+    pub fn total_steps(&self) -> u32 {
+        self.warmup_steps
+            .saturating_add(self.frames.saturating_mul(self.steps_per_frame))
+    }
+
+    /// Konfiguracja efektywna: konfiguracja uzupełniona o wersję programu. Ziarno generatora
+    /// powinno być wcześniej ustalone, aby konfiguracja pozwalała powtórzyć symulację.
+    #[inline]
+    pub fn effective(&self) -> Self {
+        Self {
+            version: Some(VERSION.to_string()),
+            ..self.clone()
+        }
+    }
+
+    /// Format pliku wynikowego: podany w parametrze `output_format` lub ustalony na
+    /// podstawie rozszerzenia pliku wynikowego.
+    #[inline]
+    pub fn resolved_output_format(&self) -> Option<OutputFormat> {
+        if self.output_format.is_some() {
+            return self.output_format;
+        }
+
+        if !self.output_command.is_empty() {
+            return Some(OutputFormat::Pipe);
+        }
+
+        // Ścieżka bez rozszerzenia oznacza katalog z sekwencją klatek
+        let extension = match Path::new(&self.output_path).extension() {
+            Some(extension) => extension.to_str()?.to_lowercase(),
+            None => return Some(OutputFormat::PngSequence),
+        };
+
+        match extension.as_str() {
+            "gif" => Some(OutputFormat::Gif),
+            // Rozszerzenie .png oznacza animację APNG (a nie pojedynczy obraz), ponieważ
+            // zapisywana jest zawsze cała animacja, a sekwencję klatek PNG wybiera się
+            // ścieżką bez rozszerzenia
+            "png" | "apng" => Some(OutputFormat::Apng),
+            "webp" => Some(OutputFormat::WebP),
+            "y4m" => Some(OutputFormat::Y4m),
+            "mp4" | "webm" | "mkv" | "mov" => Some(OutputFormat::Pipe),
+            _ => None,
+        }
+    }
+
+    /// Domyślna wartość dla paramteru: version
+    const fn default_version() -> Option<String> {
+        None
+    }
+
+    /// Domyślna wartość dla paramteru: steps_per_frame
+    const fn default_steps_per_frame() -> u32 {
+        1
+    }
+
+    /// Domyślna wartość dla paramteru: warmup_steps
+    const fn default_warmup_steps() -> u32 {
+        0
+    }
+
+    /// Domyślna wartość dla paramteru: output_format
+    const fn default_output_format() -> Option<OutputFormat> {
+        None
+    }
+
+    /// Domyślna wartość dla paramteru: output_command
+    const fn default_output_command() -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Domyślna wartość dla paramteru: gif_quality
+    const fn default_gif_quality() -> u32 {
+        80
+    }
+
+    /// Domyślna wartość dla paramteru: seed
+    const fn default_seed() -> Option<u64> {
+        None
+    }
+
     /// Domyślna wartość dla paramteru: alive_fraction
     const fn default_alive_fraction() -> f32 {
         0.5
@@ -104,6 +548,96 @@ impl Configuration {
         0.075
     }
 
+    /// Domyślna wartość dla paramteru: mortality_age
+    const fn default_mortality_age() -> u32 {
+        1500
+    }
+
+    /// Domyślna wartość dla paramteru: mortality_probability
+    const fn default_mortality_probability() -> f32 {
+        0.0
+    }
+
+    /// Domyślna wartość dla paramteru: mortality_exponent
+    const fn default_mortality_exponent() -> f32 {
+        4.0
+    }
+
+    /// Domyślna wartość dla paramteru: snag_inflammability
+    const fn default_snag_inflammability() -> f32 {
+        0.3
+    }
+
+    /// Domyślna wartość dla paramteru: snag_decay_rate
+    const fn default_snag_decay_rate() -> f32 {
+        0.002
+    }
+
+    /// Domyślna wartość dla paramteru: firefighter_count
+    const fn default_firefighter_count() -> u32 {
+        0
+    }
+
+    /// Domyślna wartość dla paramteru: firefighter_strategy
+    const fn default_firefighter_strategy() -> FirefightingStrategy {
+        FirefightingStrategy::Nearest
+    }
+
+    /// Domyślna wartość dla paramteru: firefighter_speed
+    const fn default_firefighter_speed() -> u32 {
+        1
+    }
+
+    /// Domyślna wartość dla paramteru: firefighter_radius
+    const fn default_firefighter_radius() -> u32 {
+        2
+    }
+
+    /// Domyślna wartość dla paramteru: firefighter_wetting_steps
+    const fn default_firefighter_wetting_steps() -> u32 {
+        10
+    }
+
+    /// Domyślna wartość dla paramteru: assets
+    fn default_assets() -> Vec<Asset> {
+        Vec::new()
+    }
+
+    /// Domyślna wartość dla paramteru: management_interval
+    const fn default_management_interval() -> u32 {
+        0
+    }
+
+    /// Domyślna wartość dla paramteru: management_action
+    const fn default_management_action() -> ManagementAction {
+        ManagementAction::ControlledBurn
+    }
+
+    /// Domyślna wartość dla paramteru: management_strategy
+    const fn default_management_strategy() -> ManagementStrategy {
+        ManagementStrategy::RandomPatches
+    }
+
+    /// Domyślna wartość dla paramteru: management_fraction
+    const fn default_management_fraction() -> f32 {
+        0.05
+    }
+
+    /// Domyślna wartość dla paramteru: management_patch_size
+    const fn default_management_patch_size() -> u32 {
+        8
+    }
+
+    /// Domyślna wartość dla paramteru: management_regions
+    fn default_management_regions() -> Vec<(u32, u32, u32, u32)> {
+        Vec::new()
+    }
+
+    /// Domyślna wartość dla paramteru: management_baseline
+    const fn default_management_baseline() -> bool {
+        true
+    }
+
     /// Domyślna wartość dla paramteru: forest_color_palette
     fn default_forest_color_palette() -> Vec<(u8, u8, u8)> {
         vec![
@@ -125,312 +659,530 @@ impl Configuration {
     const fn default_ground_color() -> (u8, u8, u8) {
         (2, 12, 5)
     }
+
+    /// Domyślna wartość dla paramteru: snag_color
+    const fn default_snag_color() -> (u8, u8, u8) {
+        (112, 84, 62)
+    }
+
+    /// Domyślna wartość dla paramteru: firefighter_color
+    const fn default_firefighter_color() -> (u8, u8, u8) {
+        (40, 160, 255)
+    }
 }
 
-/// Funkcja wyciągająca ścieżkę do pliku z konfiguracją z parametrów wywołania programu.
-#[inline]
-pub fn get_configuration_file() -> Result<String, String> {
-    let arguments: Vec<String> = std::env::args().collect();
+/// Wersja programu zapisywana w konfiguracji efektywnej.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
-    if arguments.len() < 2 {
-        Err(format!(
-            "{}\n\nSzczegóły:\n    Nie podano ścieżki do pliku konfiguracyjnego .ron.\n",
-            style("Błąd parametrów!").red().bold()
-        ))
-    } else if arguments.len() > 2 {
-        Err(format!(
-            "{}\n\nSzczegóły:\n    Podano zbyt wiele parametrów.\n",
-            style("Błąd parametrów!").red().bold()
-        ))
-    } else {
-        Ok(arguments[1].clone())
+/// Format pliku konfiguracyjnego.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigurationFormat {
+    /// RON (Rusty Object Notation).
+    Ron,
+
+    /// TOML.
+    Toml,
+
+    /// JSON.
+    Json,
+
+    /// YAML.
+    Yaml,
+}
+
+impl ConfigurationFormat {
+    /// Format pliku ustalony na podstawie rozszerzenia (.ron, .toml, .json, .yaml lub .yml).
+    /// Pliki o innych rozszerzeniach są traktowane jak pliki RON.
+    #[inline]
+    pub fn from_path(path: &str) -> Self {
+        let extension = Path::new(path)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_lowercase);
+
+        match extension.as_deref() {
+            Some("toml") => Self::Toml,
+            Some("json") => Self::Json,
+            Some("yaml" | "yml") => Self::Yaml,
+            _ => Self::Ron,
+        }
     }
 }
 
-/// Funkcja ładująca konfigurację z pliku .ron.
+/// Funkcja ładująca konfigurację z pliku w formacie ustalonym na podstawie rozszerzenia
+/// (RON, TOML, JSON lub YAML). Konfiguracja może dziedziczyć parametry z innego pliku lub
+/// presetu (parametr `extends`, ścieżki względne są ustalane względem katalogu pliku).
+/// Formaty TOML i YAML wymagają cechy `cli`, a odczyt plików cechy `fs`.
+#[cfg(feature = "fs")]
 #[inline]
-pub fn load_configuration(path: &str) -> Result<Configuration, String> {
-    let config_file = match File::open(Path::new(path)) {
-        Ok(config_file) => config_file,
-        Err(error) => {
-            return Err(format!(
-                "{} ({})\n\nSzczegóły:\n    {}\n",
-                style("Błąd podczas otwierania pliku konfiguracyjnego!")
-                    .red()
-                    .bold(),
-                style(format!("\"{}\"", path)).cyan().italic().bold(),
-                error
-            ));
+pub fn load_configuration(path: &str) -> Result<Configuration, ForestFireError> {
+    let text = std::fs::read_to_string(Path::new(path))
+        .map_err(|error| ForestFireError::io(Operation::LoadConfiguration, path, error))?;
+
+    presets::resolve_configuration(&text, ConfigurationFormat::from_path(path), Some(path))
+}
+
+/// Funkcja parsująca konfigurację zapisaną w formacie RON.
+#[inline]
+pub fn parse_configuration(text: &str) -> Result<Configuration, ForestFireError> {
+    presets::resolve_configuration(text, ConfigurationFormat::Ron, None)
+}
+
+/// Funkcja parsująca konfigurację zapisaną w podanym formacie. Parametry pominięte
+/// w konfiguracji przyjmują te same wartości domyślne niezależnie od formatu.
+#[cfg(feature = "cli")]
+#[inline]
+pub fn parse_configuration_as(
+    text: &str,
+    format: ConfigurationFormat,
+) -> Result<Configuration, ForestFireError> {
+    presets::resolve_configuration(text, format, None)
+}
+
+/// Funkcja zapisująca konfigurację w podanym formacie (wraz z wartościami domyślnymi
+/// parametrów pominiętych w pliku źródłowym).
+#[cfg(feature = "cli")]
+#[inline]
+pub fn serialize_configuration(
+    config: &Configuration,
+    format: ConfigurationFormat,
+) -> Result<String, String> {
+    match format {
+        ConfigurationFormat::Ron => ron::ser::to_string_pretty(
+            config,
+            ron::ser::PrettyConfig::new()
+                .struct_names(true)
+                .extensions(Extensions::IMPLICIT_SOME),
+        )
+        .map_err(|error| error.to_string()),
+        ConfigurationFormat::Toml => {
+            // Zapis przez JSON: liczby f32 są zapisywane w najkrótszej postaci (0.005 zamiast
+            // 0.004999999888241291), a wartości None są pomijane (TOML nie ma odpowiednika
+            // wartości null)
+            let text = serde_json::to_string(config).map_err(|error| error.to_string())?;
+            let mut value: serde_json::Value =
+                serde_json::from_str(&text).map_err(|error| error.to_string())?;
+
+            remove_nulls(&mut value);
+
+            toml::to_string(&value).map_err(|error| error.to_string())
         }
-    };
-    let config_reader = BufReader::new(config_file);
-    let config: Configuration = match from_reader(config_reader) {
-        Ok(config) => config,
-        Err(error) => {
-            return Err(format!(
-                "{} ({})\n\nSzczegóły:\n    {} (serde error: https://serde.rs/)\n",
-                style("Błąd podczas parsowania pliku konfiguracyjnego!")
-                    .red()
-                    .bold(),
-                style(format!("\"{}\"", path)).cyan().italic().bold(),
-                error
-            ));
+        ConfigurationFormat::Json => {
+            serde_json::to_string_pretty(config).map_err(|error| error.to_string())
         }
-    };
+        ConfigurationFormat::Yaml => {
+            serde_yaml::to_string(config).map_err(|error| error.to_string())
+        }
+    }
+}
+
+/// Usunięcie wartości null z obiektów (również zagnieżdżonych).
+#[cfg(feature = "cli")]
+#[inline]
+fn remove_nulls(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(object) => {
+            object.retain(|_, value| !value.is_null());
+            object.values_mut().for_each(remove_nulls);
+        }
+        serde_json::Value::Array(array) => array.iter_mut().for_each(remove_nulls),
+        _ => {}
+    }
+}
+
+/// Funkcja zapisująca konfigurację do pliku w formacie ustalonym na podstawie rozszerzenia.
+#[cfg(feature = "cli")]
+#[inline]
+pub fn save_configuration(config: &Configuration, path: &str) -> Result<(), ForestFireError> {
+    let text = serialize_configuration(config, ConfigurationFormat::from_path(path))
+        .map_err(|error| ForestFireError::encoding(Operation::SaveConfiguration, path, error))?;
 
-    Ok(config)
+    std::fs::write(Path::new(path), text)
+        .map_err(|error| ForestFireError::io(Operation::SaveConfiguration, path, error))
 }
 
-/// Funkcja walidująca poprawność parametrów w konfiguracji.
+/// Rozszerzenie dopisywane do ścieżki pliku wynikowego w nazwie pliku z konfiguracją
+/// efektywną (np. `green_tea.gif.config.ron`).
+pub const EFFECTIVE_CONFIGURATION_SUFFIX: &str = ".config.ron";
+
+/// Ścieżka pliku z konfiguracją efektywną zapisywanego obok pliku (lub katalogu) wynikowego.
 #[inline]
-pub fn validate_configuration(config: &Configuration) -> Result<(), String> {
-    // Sprawdzenie dla parametru: frames
-    if config.frames < 1 {
-        return Err(format!(
-            "{}\n\nSzczegóły:\n    Parametr {} musi przyjmować wartości większe od 0, podano: {}\n",
-            style("Błąd konfiguracji!").red().bold(),
-            style("\"frame_rate\"").yellow().bold(),
-            config.frames
-        ));
+pub fn effective_configuration_path(output_path: &str) -> String {
+    let output_path = output_path.trim_end_matches(['/', '\\']);
+
+    format!("{}{}", output_path, EFFECTIVE_CONFIGURATION_SUFFIX)
+}
+
+/// Funkcja zapisująca konfigurację efektywną (ze wszystkimi parametrami, ziarnem generatora
+/// i wersją programu), która pozwala dokładnie powtórzyć symulację.
+#[cfg(feature = "cli")]
+#[inline]
+pub fn save_effective_configuration(
+    config: &Configuration,
+    path: &str,
+) -> Result<(), ForestFireError> {
+    save_configuration(&config.effective(), path)
+}
+
+/// Funkcja nadpisująca parametry konfiguracji wartościami w postaci `parametr=wartość`.
+/// Wartości są interpretowane w składni JSON lub RON, a w przypadku niepowodzenia jako napisy.
+#[inline]
+pub fn override_configuration(
+    config: &Configuration,
+    overrides: &[String],
+) -> Result<Configuration, ForestFireError> {
+    if overrides.is_empty() {
+        return Ok(config.clone());
     }
 
-    // Sprawdzenie dla parametru: frame_rate
-    if config.frame_rate < 1 || config.frame_rate > 30 {
-        return Err(format!(
-            "{}\n\nSzczegóły:\n    Parametr {} musi przyjmować wartości z zakresu 1..30, podano: {}\n",
-            style("Błąd konfiguracji!").red().bold(),
-            style("\"frame_rate\"").yellow().bold(),
-            config.frame_rate
-        ));
+    let mut value = serde_json::to_value(config).unwrap();
+
+    for assignment in overrides {
+        let (name, raw_value) = assignment
+            .split_once('=')
+            .map(|(name, raw_value)| (name.trim(), raw_value.trim()))
+            .ok_or_else(|| {
+                ForestFireError::parse(
+                    Operation::OverrideConfiguration,
+                    None,
+                    tr!(
+                        "override \"{}\" must have the form parameter=value",
+                        "nadpisanie \"{}\" musi mieć postać parametr=wartość",
+                        assignment
+                    ),
+                )
+            })?;
+
+        // Parametry o wartości `None` są pomijane przy zapisie, nazwy są więc sprawdzane
+        // w definicjach parametrów, a nie w zapisanej konfiguracji
+        if !PARAMETERS.iter().any(|definition| definition.name == name) {
+            return Err(ForestFireError::parse(
+                Operation::OverrideConfiguration,
+                None,
+                tr!(
+                    "unknown configuration parameter \"{}\"",
+                    "nieznany parametr konfiguracji \"{}\"",
+                    name
+                ),
+            ));
+        }
+
+        value[name] = parse_override_value(raw_value);
     }
 
-    // Sprawdzenie dla parametru: resolution
-    if config.resolution.0 < 256
-        || config.resolution.0 > 4096
-        || config.resolution.1 < 256
-        || config.resolution.1 > 2160
-    {
-        return Err(format!(
-            "{}\n\nSzczegóły:\n    Parametr {} musi przyjmować wartości z zakresu \
-            (256..4096, 256..2160), podano: {:?}\n",
-            style("Błąd konfiguracji!").red().bold(),
-            style("\"resolution\"").yellow().bold(),
-            config.resolution
-        ));
+    serde_json::from_value(value)
+        .map_err(|error| ForestFireError::json(Operation::OverrideConfiguration, None, error))
+}
+
+/// Interpretacja wartości nadpisującej parametr (JSON, RON lub napis).
+#[inline]
+fn parse_override_value(raw_value: &str) -> serde_json::Value {
+    if let Ok(value) = serde_json::from_str(raw_value) {
+        return value;
     }
 
-    // Sprawdzenie dla paramteru: cell_size
-    if config.resolution.0 % config.cell_size != 0 || config.resolution.1 % config.cell_size != 0 {
-        return Err(format!(
-            "{}\n\nSzczegóły:\n    Parametr {} musi przyjmować taką wartość aby dzielć \
-            rozdzielczość poziomą i pionową bez reszty,\n    podano: ({} % {} = {}, {} % {} = {})\n",
-            style("Błąd konfiguracji!").red().bold(),
-            style("\"cell_size\"").yellow().bold(),
-            config.resolution.0,
-            config.cell_size,
-            config.resolution.0 % config.cell_size,
-            config.resolution.1,
-            config.cell_size,
-            config.resolution.1 % config.cell_size
-        ));
+    match ron::from_str::<ron::Value>(raw_value).map(serde_json::to_value) {
+        Ok(Ok(value)) if !value.is_null() => value,
+        _ => serde_json::Value::String(raw_value.to_string()),
     }
+}
 
-    // Sprawdzenie dla parametru: alive_fraction
-    if config.alive_fraction < 0.0 || config.alive_fraction > 1.0 {
-        return Err(format!(
-            "{}\n\nSzczegóły:\n    Parametr {} musi przyjmować wartości z zakresu \
-            0.0..1.0, podano: {:?}\n",
-            style("Błąd konfiguracji!").red().bold(),
-            style("\"alive_fraction\"").yellow().bold(),
-            config.alive_fraction
-        ));
+/// Największa liczba klatek na sekundę animacji GIF. Opóźnienia klatek GIF są całkowitymi
+/// liczbami setnych części sekundy, a przeglądarki i większość programów wydłużają opóźnienia
+/// krótsze niż 2/100 s (do około 1/10 s), przez co animacja byłaby odtwarzana zbyt wolno.
+pub const GIF_MAX_FRAME_RATE: u32 = 50;
+
+/// Funkcja walidująca poprawność parametrów w konfiguracji. Zwracany błąd zawiera wszystkie
+/// naruszone reguły (a nie tylko pierwszą z nich).
+#[inline]
+pub fn validate_configuration(config: &Configuration) -> Result<(), ForestFireError> {
+    let cells_x = config.resolution.0 / config.cell_size.max(1);
+    let cells_y = config.resolution.1 / config.cell_size.max(1);
+    let mut violations = Vec::new();
+
+    // Sprawdzenie zakresów zadeklarowanych w definicjach parametrów
+    if let Some(serde_json::Value::Object(values)) = specification::as_value(config) {
+        violations.extend(PARAMETERS.iter().filter_map(|definition| {
+            definition.check_bounds(
+                values
+                    .get(definition.name)
+                    .unwrap_or(&serde_json::Value::Null),
+            )
+        }));
     }
 
-    // Sprawdzenie dla parametru: sprout_probability
-    if config.sprout_probability < 0.0 || config.sprout_probability > 1.0 {
-        return Err(format!(
-            "{}\n\nSzczegóły:\n    Parametr {} musi przyjmować wartości z zakresu \
-            0.0..1.0, podano: {:?}\n",
-            style("Błąd konfiguracji!").red().bold(),
-            style("\"sprout_probability\"").yellow().bold(),
-            config.sprout_probability
+    // Sprawdzenie dla parametru: frame_rate (ograniczenie animacji GIF)
+    if (GIF_MAX_FRAME_RATE + 1..=100).contains(&config.frame_rate)
+        && config.resolved_output_format() == Some(OutputFormat::Gif)
+    {
+        violations.push(Violation::new(
+            "frame_rate",
+            config.frame_rate,
+            tr!(
+                "at most {} for GIF animations",
+                "co najwyżej {} dla animacji GIF",
+                GIF_MAX_FRAME_RATE
+            ),
         ));
     }
 
-    // Sprawdzenie dla parametru: random_sprout_probability
-    if config.random_sprout_probability < 0.0 || config.random_sprout_probability > 1.0 {
-        return Err(format!(
-            "{}\n\nSzczegóły:\n    Parametr {} musi przyjmować wartości z zakresu \
-            0.0..1.0, podano: {:?}\n",
-            style("Błąd konfiguracji!").red().bold(),
-            style("\"sprout_probability\"").yellow().bold(),
-            config.random_sprout_probability
+    // Sprawdzenie dla parametru: output_format
+    if config.resolved_output_format().is_none() {
+        violations.push(Violation::new(
+            "output_format",
+            format!("None (output_path: {:?})", config.output_path),
+            text(
+                "set explicitly when the format cannot be inferred from the output file \
+                extension (.gif, .png, .apng, .webp, .y4m, .mp4, .webm, .mkv, .mov)",
+                "wartość podaną jawnie, jeżeli nie można ustalić formatu pliku wynikowego na \
+                podstawie rozszerzenia (.gif, .png, .apng, .webp, .y4m, .mp4, .webm, .mkv, .mov)",
+            ),
         ));
     }
 
-    // Sprawdzenie dla parametru: growth_rate
-    if config.growth_rate < 0.0 {
-        return Err(format!(
-            "{}\n\nSzczegóły:\n    Parametr {} musi przyjmować wartości większe od \
-            0.0, podano: {:?}\n",
-            style("Błąd konfiguracji!").red().bold(),
-            style("\"growth_rate\"").yellow().bold(),
-            config.growth_rate
+    // Sprawdzenie dla parametru: cell_size (dolna granica jest sprawdzana razem z zakresami)
+    if config.cell_size >= 1
+        && (!config.resolution.0.is_multiple_of(config.cell_size)
+            || !config.resolution.1.is_multiple_of(config.cell_size))
+    {
+        violations.push(Violation::new(
+            "cell_size",
+            format!(
+                "{} ({} % {} = {}, {} % {} = {})",
+                config.cell_size,
+                config.resolution.0,
+                config.cell_size,
+                config.resolution.0 % config.cell_size,
+                config.resolution.1,
+                config.cell_size,
+                config.resolution.1 % config.cell_size
+            ),
+            text(
+                "a divisor of both the horizontal and the vertical resolution",
+                "taką wartość aby dzielić rozdzielczość poziomą i pionową bez reszty",
+            ),
         ));
     }
 
-    // Sprawdzenie dla paramteru: inflammability
-    if config.inflammability < 0.0 || config.inflammability > 1.0 {
-        return Err(format!(
-            "{}\n\nSzczegóły:\n    Parametr {} musi przyjmować wartości z zakresu \
-            0.0..1.0, podano: {:?}\n",
-            style("Błąd konfiguracji!").red().bold(),
-            style("\"inflammability\"").yellow().bold(),
-            config.inflammability
-        ));
+    // Sprawdzenie dla parametru: firefighter_strategy
+    if let FirefightingStrategy::ProtectArea { x, y } = config.firefighter_strategy {
+        if x >= cells_x || y >= cells_y {
+            violations.push(Violation::new(
+                "firefighter_strategy",
+                format!("ProtectArea(x: {}, y: {})", x, y),
+                tr!(
+                    "a protected point on the board (0..{}, 0..{})",
+                    "chroniony punkt leżący na planszy (0..{}, 0..{})",
+                    cells_x,
+                    cells_y
+                ),
+            ));
+        }
     }
 
-    // Sprawdzenie dla paramteru: self_ignition_probability
-    if config.self_ignition_probability < 0.0 || config.self_ignition_probability > 1.0 {
-        return Err(format!(
-            "{}\n\nSzczegóły:\n    Parametr {} musi przyjmować wartości z zakresu \
-            0.0..1.0, podano: {:?}\n",
-            style("Błąd konfiguracji!").red().bold(),
-            style("\"self_ignition_probability\"").yellow().bold(),
-            config.self_ignition_probability
-        ));
+    // Sprawdzenie dla parametru: assets
+    for asset in config.assets.iter() {
+        if asset.rectangles.is_empty() && asset.mask_path.is_none() {
+            violations.push(Violation::new(
+                format!("assets[\"{}\"]", asset.name),
+                text("no rectangles and no mask", "brak prostokątów i maski"),
+                text("rectangles or a mask", "prostokąty lub maskę"),
+            ));
+        }
+
+        for rectangle in asset.rectangles.iter() {
+            if !rectangle_on_board(*rectangle, cells_x, cells_y) {
+                violations.push(Violation::new(
+                    format!("assets[\"{}\"].rectangles", asset.name),
+                    format!("{:?}", rectangle),
+                    tr!(
+                        "non-empty rectangles on the board ({} x {})",
+                        "niepuste prostokąty leżące na planszy ({} x {})",
+                        cells_x,
+                        cells_y
+                    ),
+                ));
+            }
+        }
     }
 
-    // Sprawdzenie dla parametru: burning_rate
-    if config.burning_rate <= 0.0 {
-        return Err(format!(
-            "{}\n\nSzczegóły:\n    Parametr {} musi przyjmować wartości większe od \
-            0.0, podano: {:?}\n",
-            style("Błąd konfiguracji!").red().bold(),
-            style("\"burning_rate\"").yellow().bold(),
-            config.burning_rate
-        ));
+    // Sprawdzenie dla parametru: management_regions
+    for region in config.management_regions.iter() {
+        if !rectangle_on_board(*region, cells_x, cells_y) {
+            violations.push(Violation::new(
+                "management_regions",
+                format!("{:?}", region),
+                tr!(
+                    "non-empty rectangles on the board ({} x {})",
+                    "niepuste prostokąty leżące na planszy ({} x {})",
+                    cells_x,
+                    cells_y
+                ),
+            ));
+        }
     }
 
     // Sprawdzenie dla parametru: forest_color_palette
     if config.forest_color_palette.is_empty() {
-        return Err(format!(
-            "{}\n\nSzczegóły:\n    Lista {} nie może być pusta\n",
-            style("Błąd konfiguracji!").red().bold(),
-            style("\"forest_color_palette\"").yellow().bold()
+        violations.push(Violation::new(
+            "forest_color_palette",
+            "[]",
+            text("a non-empty list of colours", "niepustą listę kolorów"),
         ));
     }
 
-    Ok(())
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(ForestFireError::Validation { violations })
+    }
 }
 
-/// Funkcja wypisująca specyfikację konfiguracji.
+/// Sprawdzenie, czy prostokąt (x, y, szerokość, wysokość) jest niepusty i leży na planszy.
+#[inline]
+fn rectangle_on_board(rectangle: (u32, u32, u32, u32), cells_x: u32, cells_y: u32) -> bool {
+    let (x, y, width, height) = rectangle;
+
+    width >= 1
+        && height >= 1
+        && x.saturating_add(width) <= cells_x
+        && y.saturating_add(height) <= cells_y
+}
+
+/// Funkcja wypisująca specyfikację konfiguracji generowaną na podstawie struktury
+/// `Configuration`: nazwę (parametry wymagane na zielono, opcjonalne na żółto), typ,
+/// dopuszczalny zakres, opis i wartość domyślną każdego parametru.
+#[cfg(feature = "cli")]
 #[inline]
 pub fn print_configuration_specification() {
+    let parameters = specification::parameters();
+    let rows: Vec<(&Parameter, String)> = parameters
+        .iter()
+        .map(|parameter| (parameter, parameter.describe_bounds()))
+        .collect();
+
+    // Szerokości kolumn: nazwy, typu i zakresu
+    let width = |column: &dyn Fn(&(&Parameter, String)) -> usize, header: &str| {
+        rows.iter()
+            .map(column)
+            .chain([header.chars().count()])
+            .max()
+            .unwrap_or(0)
+    };
+
+    let headers = [
+        text("Parameter", "Parametr"),
+        text("Type", "Typ"),
+        text("Range", "Zakres"),
+        text("Description", "Opis"),
+    ];
+    let name_width = width(
+        &|(parameter, _)| parameter.definition.name.len(),
+        headers[0],
+    );
+    let type_width = width(
+        &|(parameter, _)| parameter.definition.type_name.len(),
+        headers[1],
+    );
+    let bounds_width = width(&|(_, bounds)| bounds.chars().count(), headers[2]);
+
+    println!(
+        "\n\n{}\n\n{}",
+        style(text(
+            "Configuration file specification:",
+            "Specyfikacja pliku konfiguracyjnego:"
+        ))
+        .blue()
+        .bold(),
+        style(format!(
+            "{:<name_width$}  {:<type_width$}  {:<bounds_width$}  {}",
+            headers[0], headers[1], headers[2], headers[3]
+        ))
+        .bold()
+    );
+
+    for (parameter, bounds) in &rows {
+        let name = format!("{:<name_width$}", parameter.definition.name);
+        let name = if parameter.required() {
+            style(name).green()
+        } else {
+            style(name).yellow()
+        };
+
+        let default = match &parameter.default {
+            Some(default) => tr!("default: {}", "domyślnie: {}", default),
+            None => text("required", "wymagany").to_string(),
+        };
+
+        println!(
+            "{}  {:<type_width$}  {:<bounds_width$}  {} ({})",
+            name,
+            parameter.definition.type_name,
+            bounds,
+            parameter.description(),
+            style(default).bold()
+        );
+    }
+
     println!(
-        "{}",
-        format!(
-            "\n\n{}\n\n{}\n\
-                ____________________________________________________________________________________________________\
-                \n1  |{}(\
-                \n2  |    {} <u32>,                                  <- Liczba klatek symulacji\
-                \n3  |    {} <u32>,                              <- Liczba klatek na sekundę animacji\
-                \n4  |    {} <String>,                          <- Ścieżka do pliku wynikowego\
-                \n5  |    {} <(u32, u32)>,                       <- Rozdzielczość\
-                \n6  |    {} <u32>,                               <- Wielkość komórki\
-                \n7  |    {} <f32>,                          <- Frakcja komórek zajętych przez drzewa\
-                \n8  |    {} <f32>,                      <- Prawdopodobieństwo wykiełkowania\
-                \n9  |    {} <f32>,               <- Prawdopodobieństwo losowego wykiełkowania\
-                \n10 |    {} <f32>,                             <- Tempo wzrostu\
-                \n11 |    {} <f32>,                          <- Łatwopalność\
-                \n12 |    {} <f32>,               <- Prawdopodobieństwo samozapłonu\
-                \n13 |    {} <f32>,                            <- Szybkość spalania\
-                \n14 |    {} <[(u8, u8, u8), ...]>,    <- Paleta kolorystyczna lasu\
-                \n15 |    {} <(u8, u8, u8)>,                     <- Kolor ognia\
-                \n16 |    {} <(u8, u8, u8)>,                   <- Kolor podłoża\
-                \n17 |)\n",
-            style("Specyfikacja pliku konfiguracyjnego:").blue().bold(),
-            style("example.ron").bold(),
-            style("Configuration").cyan().bold(),
-            style("frames:").green(),
-            style("frame_rate:").green(),
-            style("output_path:").green(),
-            style("resolution:").green(),
-            style("cell_size:").green(),
-            style("alive_fraction:").yellow(),
-            style("sprout_probability:").yellow(),
-            style("random_sprout_probability:").yellow(),
-            style("growth_rate:").yellow(),
-            style("inflammability:").yellow(),
-            style("self_ignition_probability:").yellow(),
-            style("burning_rate:").yellow(),
-            style("forest_color_palette:").yellow(),
-            style("fire_color:").yellow(),
-            style("ground_color:").yellow()
+        "\n{}",
+        tr!(
+            "A configuration can inherit the parameters of a preset ({}) or of another file with \
+                the \"extends\" parameter. The JSON Schema of the configuration is written with \
+                \"print-spec --schema <FILE>\".",
+            "Konfiguracja może dziedziczyć parametry presetu ({}) lub innego pliku (parametr \
+                \"extends\"). Schemat JSON Schema konfiguracji zapisuje polecenie \
+                \"print-spec --schema <PLIK>\".",
+            presets::PRESETS.map(|(name, _)| name).join(", ")
         )
     );
 }
 
-/// Funkcja wypisująca aktualnie zadaną konfigurację.
+/// Funkcja zapisująca schemat JSON Schema konfiguracji (opisy parametrów w aktualnym języku).
+#[cfg(feature = "fs")]
+#[inline]
+pub fn save_configuration_schema(path: &str) -> Result<(), ForestFireError> {
+    let text = serde_json::to_string_pretty(&specification::json_schema())
+        .map_err(|error| ForestFireError::encoding(Operation::SaveSchema, path, error))?;
+
+    std::fs::write(Path::new(path), text + "\n")
+        .map_err(|error| ForestFireError::io(Operation::SaveSchema, path, error))
+}
+
+/// Funkcja wypisująca aktualnie zadaną konfigurację (parametry wymagane na zielono,
+/// opcjonalne na żółto, wartości w postaci JSON).
+#[cfg(feature = "cli")]
 #[inline]
 pub fn print_configuration(config: &Configuration, configuration_file: &str) {
+    let values = specification::as_value(config).unwrap_or_default();
+
     println!(
-        "{}",
-        format!(
-            "{} ({}):\n\
-                \n{}(\
-                \n    {} {},\
-                \n    {} {},\
-                \n    {} {},\
-                \n    {} {},\
-                \n    {} {},\
-                \n    {} {},\
-                \n    {} {},\
-                \n    {} {},\
-                \n    {} {},\
-                \n    {} {},\
-                \n    {} {},\
-                \n    {} {},\
-                \n    {} {},\
-                \n    {} {},\
-                \n    {} {},\
-                \n)",
-            style("Parametry symulacji").blue().bold(),
-            style(format!("\"{}\"", configuration_file))
-                .cyan()
-                .italic()
-                .bold(),
-            style("Configuration").cyan().bold(),
-            style("frames:").green(),
-            style(format!("{}", config.frames)).bold(),
-            style("frame_rate:").green(),
-            style(format!("{}", config.frame_rate)).bold(),
-            style("output_path:").green(),
-            style(format!("\"{}\"", config.output_path)).bold(),
-            style("resolution:").green(),
-            style(format!("{:?}", config.resolution)).bold(),
-            style("cell_size:").green(),
-            style(format!("{}", config.cell_size)).bold(),
-            style("alive_fraction:").yellow(),
-            style(format!("{}", config.alive_fraction)).bold(),
-            style("sprout_probability:").yellow(),
-            style(format!("{}", config.sprout_probability)).bold(),
-            style("random_sprout_probability:").yellow(),
-            style(format!("{}", config.random_sprout_probability)).bold(),
-            style("growth_rate:").yellow(),
-            style(format!("{}", config.growth_rate)).bold(),
-            style("inflammability:").yellow(),
-            style(format!("{}", config.inflammability)).bold(),
-            style("self_ignition_probability:").yellow(),
-            style(format!("{}", config.self_ignition_probability)).bold(),
-            style("burning_rate:").yellow(),
-            style(format!("{}", config.burning_rate)).bold(),
-            style("forest_color_palette:").yellow(),
-            style(format!("{:?}", config.forest_color_palette)).bold(),
-            style("fire_color:").yellow(),
-            style(format!("{:?}", config.fire_color)).bold(),
-            style("ground_color:").yellow(),
-            style(format!("{:?}", config.ground_color)).bold()
-        )
+        "{} ({}):\n\n{}(",
+        style(text("Simulation parameters", "Parametry symulacji"))
+            .blue()
+            .bold(),
+        style(format!("\"{}\"", configuration_file))
+            .cyan()
+            .italic()
+            .bold(),
+        style("Configuration").cyan().bold()
     );
+
+    for parameter in specification::parameters() {
+        let name = format!("{}:", parameter.definition.name);
+        let name = if parameter.required() {
+            style(name).green()
+        } else {
+            style(name).yellow()
+        };
+
+        // Parametry pomijane przy zapisie (`None`) mają wartość `null`
+        let value = values
+            .get(parameter.definition.name)
+            .unwrap_or(&serde_json::Value::Null);
+
+        println!("    {} {},", name, style(value).bold());
+    }
+
+    println!(")");
 }
@@ -0,0 +1,270 @@
+use crate::{
+    configuration::{override_configuration, Configuration},
+    error::{ForestFireError, Operation},
+    locale::text,
+    simulation::simulate_parallel,
+    statistics::{Summary, SUMMARY_COLUMNS},
+};
+
+use console::style;
+use image::{Rgb, RgbImage};
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Punkty kontrolne skali kolorów mapy prawdopodobieństwa spalenia (od 0.0 do 1.0).
+const HEATMAP_COLORS: [(f32, (u8, u8, u8)); 5] = [
+    (0.0, (0, 0, 4)),
+    (0.25, (87, 16, 110)),
+    (0.5, (188, 55, 84)),
+    (0.75, (249, 142, 9)),
+    (1.0, (252, 255, 164)),
+];
+
+/// Wyniki zespołu symulacji zbierane w trakcie przebiegów.
+struct EnsembleAccumulator {
+    /// Liczba przebiegów, w których dana komórka została objęta ogniem.
+    burn_counts: Vec<u32>,
+
+    /// Statystyki zbiorcze kolejnych przebiegów.
+    summaries: Vec<Summary>,
+
+    /// Pierwszy błąd, który wystąpił w trakcie przebiegów.
+    error: Option<ForestFireError>,
+}
+
+/// Kolor mapy prawdopodobieństwa spalenia (interpolacja liniowa między punktami kontrolnymi).
+#[inline]
+fn heatmap_color(probability: f32) -> Rgb<u8> {
+    let probability = probability.clamp(0.0, 1.0);
+
+    for window in HEATMAP_COLORS.windows(2) {
+        let (start, start_color) = window[0];
+        let (end, end_color) = window[1];
+
+        if probability <= end {
+            let t = (probability - start) / (end - start);
+            let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t) as u8;
+
+            return Rgb([
+                mix(start_color.0, end_color.0),
+                mix(start_color.1, end_color.1),
+                mix(start_color.2, end_color.2),
+            ]);
+        }
+    }
+
+    let (_, color) = HEATMAP_COLORS[HEATMAP_COLORS.len() - 1];
+    Rgb([color.0, color.1, color.2])
+}
+
+/// Średnie i wariancje (nieobciążone) kolejnych statystyk zbiorczych zespołu symulacji
+/// (w kolejności `SUMMARY_COLUMNS`).
+#[inline]
+fn summary_moments(summaries: &[Summary]) -> Vec<(f64, f64)> {
+    let count = summaries.len() as f64;
+
+    (0..SUMMARY_COLUMNS.len())
+        .map(|column| {
+            let mean = summaries.iter().map(|summary| summary[column]).sum::<f64>() / count;
+            let variance = if summaries.len() > 1 {
+                summaries
+                    .iter()
+                    .map(|summary| (summary[column] - mean).powi(2))
+                    .sum::<f64>()
+                    / (count - 1.0)
+            } else {
+                0.0
+            };
+
+            (mean, variance)
+        })
+        .collect()
+}
+
+/// Zespół symulacji Monte Carlo: `replicas` przebiegów tego samego scenariusza z różnymi
+/// ziarnami (przebieg `n` otrzymuje ziarno `seed + n`). Wynikiem jest mapa prawdopodobieństwa
+/// spalenia (obraz PNG i siatka liczbowa CSV) oraz średnie i wariancje statystyk zbiorczych
+/// (wypisywane i zapisywane w pliku CSV `summary_path`).
+#[inline]
+pub fn ensemble(
+    config: &Configuration,
+    replicas: usize,
+    jobs: usize,
+    heatmap_path: &str,
+    grid_path: &str,
+    summary_path: &str,
+) -> Result<(), ForestFireError> {
+    if replicas == 0 {
+        return Err(ForestFireError::validation(
+            "replicas",
+            replicas,
+            text("greater than 0", "wartości większe od 0"),
+        ));
+    }
+
+    let cells_x = config.resolution.0 / config.cell_size;
+    let cells_y = config.resolution.1 / config.cell_size;
+    let base_seed = config.seed.unwrap_or(0);
+
+    let configs = (0..replicas)
+        .map(|index| {
+            override_configuration(
+                config,
+                &[format!("seed={}", base_seed.wrapping_add(index as u64))],
+            )
+        })
+        .collect::<Result<Vec<Configuration>, ForestFireError>>()?;
+
+    let accumulator = Mutex::new(EnsembleAccumulator {
+        burn_counts: vec![0; (cells_x * cells_y) as usize],
+        summaries: Vec::with_capacity(replicas),
+        error: None,
+    });
+
+    simulate_parallel(
+        &configs,
+        jobs,
+        text("Simulation ensemble progress:", "Postęp zespołu symulacji:"),
+        |_, result| {
+            let mut accumulator = accumulator.lock().unwrap();
+
+            match result {
+                Ok(statistics) => {
+                    for (count, burned) in accumulator
+                        .burn_counts
+                        .iter_mut()
+                        .zip(statistics.burn_map(config))
+                    {
+                        *count += burned as u32;
+                    }
+
+                    accumulator.summaries.push(statistics.summary());
+                }
+                Err(error) => {
+                    accumulator.error.get_or_insert(error);
+                }
+            }
+        },
+    );
+
+    let accumulator = accumulator.into_inner().unwrap();
+
+    if let Some(error) = accumulator.error {
+        return Err(error);
+    }
+
+    let probabilities: Vec<f32> = accumulator
+        .burn_counts
+        .iter()
+        .map(|&count| count as f32 / replicas as f32)
+        .collect();
+
+    // Mapa prawdopodobieństwa spalenia w postaci obrazu
+    let heatmap = RgbImage::from_fn(config.resolution.0, config.resolution.1, |x, y| {
+        heatmap_color(
+            probabilities[((y / config.cell_size) * cells_x + x / config.cell_size) as usize],
+        )
+    });
+
+    heatmap
+        .save(Path::new(heatmap_path))
+        .map_err(|error| ForestFireError::encoding(Operation::SaveHeatmap, heatmap_path, error))?;
+
+    // Mapa prawdopodobieństwa spalenia w postaci siatki liczbowej
+    let write_error = |error: std::io::Error| {
+        ForestFireError::io(Operation::SaveProbabilityGrid, grid_path, error)
+    };
+
+    let mut writer = BufWriter::new(File::create(Path::new(grid_path)).map_err(write_error)?);
+
+    for row in probabilities.chunks(cells_x as usize) {
+        let row: Vec<String> = row
+            .iter()
+            .map(|probability| probability.to_string())
+            .collect();
+        writeln!(writer, "{}", row.join(",")).map_err(write_error)?;
+    }
+
+    writer.flush().map_err(write_error)?;
+
+    // Średnie i wariancje statystyk zbiorczych
+    let moments = summary_moments(&accumulator.summaries);
+
+    let write_error =
+        |error: std::io::Error| ForestFireError::io(Operation::SaveSummary, summary_path, error);
+
+    let mut writer = BufWriter::new(File::create(Path::new(summary_path)).map_err(write_error)?);
+
+    writeln!(writer, "statistic,mean,variance").map_err(write_error)?;
+    for (name, (mean, variance)) in SUMMARY_COLUMNS.iter().zip(moments.iter()) {
+        writeln!(writer, "{},{},{}", name, mean, variance).map_err(write_error)?;
+    }
+
+    writer.flush().map_err(write_error)?;
+
+    print_ensemble_summary(accumulator.summaries.len(), &moments);
+
+    println!(
+        "\n{} {}\n{} {}\n{} {}",
+        style(text(
+            "Saved the burn probability map:",
+            "Zapisano mapę prawdopodobieństwa spalenia:"
+        ))
+        .green()
+        .bold(),
+        style(format!("\"{}\"", heatmap_path))
+            .cyan()
+            .italic()
+            .bold(),
+        style(text(
+            "Saved the burn probability grid:",
+            "Zapisano siatkę prawdopodobieństwa spalenia:"
+        ))
+        .green()
+        .bold(),
+        style(format!("\"{}\"", grid_path)).cyan().italic().bold(),
+        style(text(
+            "Saved the summary statistics:",
+            "Zapisano statystyki zbiorcze:"
+        ))
+        .green()
+        .bold(),
+        style(format!("\"{}\"", summary_path))
+            .cyan()
+            .italic()
+            .bold()
+    );
+
+    Ok(())
+}
+
+/// Funkcja wypisująca średnie i wariancje statystyk zbiorczych zespołu `runs` symulacji.
+#[inline]
+fn print_ensemble_summary(runs: usize, moments: &[(f64, f64)]) {
+    println!(
+        "\n{} ({} {}):\n\n    {:<24} {:>14} {:>18}",
+        style(text(
+            "Simulation ensemble statistics",
+            "Statystyki zespołu symulacji"
+        ))
+        .blue()
+        .bold(),
+        runs,
+        style(text("runs", "przebiegów")).bold(),
+        "",
+        style(text("mean", "średnia")).bold(),
+        style(text("variance", "wariancja")).bold()
+    );
+
+    for (name, (mean, variance)) in SUMMARY_COLUMNS.iter().zip(moments.iter()) {
+        println!(
+            "    {:<24} {:>14.3} {:>18.3}",
+            style(name).green(),
+            mean,
+            variance
+        );
+    }
+}
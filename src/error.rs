@@ -0,0 +1,371 @@
+//! Błędy zgłaszane przez bibliotekę. Opisy błędów (w aktualnym języku komunikatów) nie
+//! zawierają formatowania terminala (kolorowaniem zajmuje się program wiersza poleceń).
+
+use crate::{locale::text, tr};
+
+use std::fmt::{self, Display, Formatter};
+
+/// Czynność, podczas której wystąpił błąd (opis w aktualnym języku jest zwracany przez
+/// `Display`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    LoadConfiguration,
+    SaveConfiguration,
+    SaveSchema,
+    ParseConfiguration,
+    OverrideConfiguration,
+    ParseSweepParameter,
+    LoadSessionLog,
+    SaveSessionLog,
+    LoadAssetMask,
+    CreateOutput,
+    CreateOutputDirectory,
+    WriteOutput,
+    StartEncoder,
+    RunEncoder,
+    StartServer,
+    EncodeStreamFrame,
+    SaveHeatmap,
+    SaveProbabilityGrid,
+    SaveSummary,
+}
+
+impl Display for Operation {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::LoadConfiguration => text(
+                "loading the configuration file",
+                "wczytywania pliku konfiguracyjnego",
+            ),
+            Self::SaveConfiguration => text(
+                "saving the configuration file",
+                "zapisu pliku konfiguracyjnego",
+            ),
+            Self::SaveSchema => text(
+                "saving the configuration schema",
+                "zapisu schematu konfiguracji",
+            ),
+            Self::ParseConfiguration => {
+                text("parsing the configuration", "parsowania konfiguracji")
+            }
+            Self::OverrideConfiguration => text(
+                "overriding configuration parameters",
+                "nadpisywania parametrów konfiguracji",
+            ),
+            Self::ParseSweepParameter => text(
+                "parsing a sweep parameter",
+                "parsowania parametru przeglądu",
+            ),
+            Self::LoadSessionLog => text("loading the session log", "wczytywania dziennika sesji"),
+            Self::SaveSessionLog => text("saving the session log", "zapisu dziennika sesji"),
+            Self::LoadAssetMask => text("loading an asset mask", "wczytywania maski obiektu"),
+            Self::CreateOutput => text("creating the output file", "tworzenia pliku wynikowego"),
+            Self::CreateOutputDirectory => text(
+                "creating the output directory",
+                "tworzenia katalogu wynikowego",
+            ),
+            Self::WriteOutput => text("writing the output file", "zapisu pliku wynikowego"),
+            Self::StartEncoder => text(
+                "starting the external encoder",
+                "uruchamiania zewnętrznego kodera",
+            ),
+            Self::RunEncoder => text(
+                "running the external encoder",
+                "działania zewnętrznego kodera",
+            ),
+            Self::StartServer => text("starting the server", "uruchamiania serwera"),
+            Self::EncodeStreamFrame => text(
+                "encoding a frame of the preview stream",
+                "kodowania klatki strumienia podglądu",
+            ),
+            Self::SaveHeatmap => text(
+                "saving the burn probability map",
+                "zapisu mapy prawdopodobieństwa spalenia",
+            ),
+            Self::SaveProbabilityGrid => text(
+                "saving the burn probability grid",
+                "zapisu siatki prawdopodobieństwa spalenia",
+            ),
+            Self::SaveSummary => text("saving the summary table", "zapisu tabeli podsumowującej"),
+        })
+    }
+}
+
+/// Naruszenie reguły walidacji: parametr `field` musi przyjmować `constraint`, podano `value`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub field: String,
+    pub value: String,
+    pub constraint: String,
+}
+
+impl Violation {
+    /// Utworzenie opisu naruszenia reguły walidacji.
+    #[inline]
+    pub fn new(
+        field: impl Into<String>,
+        value: impl Display,
+        constraint: impl Into<String>,
+    ) -> Self {
+        Self {
+            field: field.into(),
+            value: value.to_string(),
+            constraint: constraint.into(),
+        }
+    }
+}
+
+impl Display for Violation {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(&tr!(
+            "parameter \"{}\" must be {}, got: {}",
+            "parametr \"{}\" musi przyjmować {}, podano: {}",
+            self.field,
+            self.constraint,
+            self.value
+        ))
+    }
+}
+
+/// Błąd modelu pożaru lasu.
+#[derive(Debug)]
+pub enum ForestFireError {
+    /// Błąd wejścia/wyjścia (plik, katalog, proces lub gniazdo sieciowe `path`).
+    Io {
+        operation: Operation,
+        path: String,
+        source: std::io::Error,
+    },
+
+    /// Błąd parsowania tekstu (wraz z pozycją w tekście, jeżeli jest znana).
+    Parse {
+        operation: Operation,
+        path: Option<String>,
+        line: Option<usize>,
+        column: Option<usize>,
+        message: String,
+    },
+
+    /// Niepoprawne wartości parametrów (wszystkie naruszone reguły walidacji).
+    Validation { violations: Vec<Violation> },
+
+    /// Błąd kodowania lub zapisu obrazów i animacji.
+    Encoding {
+        operation: Operation,
+        path: String,
+        message: String,
+    },
+}
+
+impl ForestFireError {
+    /// Błąd wejścia/wyjścia.
+    #[inline]
+    pub fn io(operation: Operation, path: impl Into<String>, source: std::io::Error) -> Self {
+        Self::Io {
+            operation,
+            path: path.into(),
+            source,
+        }
+    }
+
+    /// Błąd parsowania bez znanej pozycji w tekście.
+    #[inline]
+    pub fn parse(operation: Operation, path: Option<&str>, message: impl Display) -> Self {
+        Self::Parse {
+            operation,
+            path: path.map(str::to_string),
+            line: None,
+            column: None,
+            message: message.to_string(),
+        }
+    }
+
+    /// Błąd parsowania tekstu w formacie RON (wraz z pozycją w tekście).
+    #[inline]
+    pub fn ron(operation: Operation, path: Option<&str>, error: ron::Error) -> Self {
+        Self::Parse {
+            operation,
+            path: path.map(str::to_string),
+            line: Some(error.position.line),
+            column: Some(error.position.col),
+            message: error.code.to_string(),
+        }
+    }
+
+    /// Błąd parsowania tekstu w formacie JSON (wraz z pozycją w tekście, jeżeli jest znana).
+    #[inline]
+    pub fn json(operation: Operation, path: Option<&str>, error: serde_json::Error) -> Self {
+        let position = (error.line() > 0).then(|| (error.line(), error.column()));
+
+        Self::positioned(operation, path, position, error.to_string())
+    }
+
+    /// Błąd parsowania tekstu w formacie TOML (pozycja w tekście jest wyznaczana na podstawie
+    /// zakresu bajtów wskazanego przez parser).
+    #[cfg(feature = "cli")]
+    #[inline]
+    pub fn toml(
+        operation: Operation,
+        path: Option<&str>,
+        error: toml::de::Error,
+        text: &str,
+    ) -> Self {
+        let position = error.span().map(|span| {
+            let before = &text[..span.start.min(text.len())];
+            let line = before.matches('\n').count() + 1;
+            let column = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+
+            (line, column)
+        });
+
+        Self::Parse {
+            operation,
+            path: path.map(str::to_string),
+            line: position.map(|(line, _)| line),
+            column: position.map(|(_, column)| column),
+            message: error.message().trim_end().to_string(),
+        }
+    }
+
+    /// Błąd parsowania tekstu w formacie YAML (wraz z pozycją w tekście, jeżeli jest znana).
+    #[cfg(feature = "cli")]
+    #[inline]
+    pub fn yaml(operation: Operation, path: Option<&str>, error: serde_yaml::Error) -> Self {
+        let position = error
+            .location()
+            .map(|location| (location.line(), location.column()));
+
+        Self::positioned(operation, path, position, error.to_string())
+    }
+
+    /// Błąd parsowania, którego opis kończy się pozycją w tekście ("at line X column Y").
+    /// Pozycja jest usuwana z opisu i przechowywana osobno.
+    #[inline]
+    fn positioned(
+        operation: Operation,
+        path: Option<&str>,
+        position: Option<(usize, usize)>,
+        mut message: String,
+    ) -> Self {
+        if let Some((line, column)) = position {
+            let suffix = format!(" at line {} column {}", line, column);
+            message.truncate(message.strip_suffix(&suffix).unwrap_or(&message).len());
+        }
+
+        Self::Parse {
+            operation,
+            path: path.map(str::to_string),
+            line: position.map(|(line, _)| line),
+            column: position.map(|(_, column)| column),
+            message,
+        }
+    }
+
+    /// Niepoprawna wartość pojedynczego parametru.
+    #[inline]
+    pub fn validation(
+        field: impl Into<String>,
+        value: impl Display,
+        constraint: impl Into<String>,
+    ) -> Self {
+        Self::Validation {
+            violations: vec![Violation::new(field, value, constraint)],
+        }
+    }
+
+    /// Błąd kodowania.
+    #[inline]
+    pub fn encoding(operation: Operation, path: impl Into<String>, message: impl Display) -> Self {
+        Self::Encoding {
+            operation,
+            path: path.into(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for ForestFireError {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io {
+                operation,
+                path,
+                source,
+            } => write!(
+                formatter,
+                "{} {} (\"{}\"): {}",
+                text("Error while", "Błąd podczas"),
+                operation,
+                path,
+                source
+            ),
+            Self::Parse {
+                operation,
+                path,
+                line,
+                column,
+                message,
+            } => {
+                write!(
+                    formatter,
+                    "{} {}",
+                    text("Error while", "Błąd podczas"),
+                    operation
+                )?;
+
+                if let Some(path) = path {
+                    write!(formatter, " (\"{}\")", path)?;
+                }
+
+                if let (Some(line), Some(column)) = (line, column) {
+                    formatter.write_str(&tr!(
+                        ", line {}, column {}",
+                        ", linia {}, kolumna {}",
+                        line,
+                        column
+                    ))?;
+                }
+
+                write!(formatter, ": {}", message)
+            }
+            Self::Validation { violations } => {
+                formatter.write_str(text("Configuration error: ", "Błąd konfiguracji: "))?;
+
+                for (index, violation) in violations.iter().enumerate() {
+                    if index > 0 {
+                        formatter.write_str("; ")?;
+                    }
+
+                    write!(formatter, "{}", violation)?;
+                }
+
+                Ok(())
+            }
+            Self::Encoding {
+                operation,
+                path,
+                message,
+            } => write!(
+                formatter,
+                "{} {} (\"{}\"): {}",
+                text("Error while", "Błąd podczas"),
+                operation,
+                path,
+                message
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ForestFireError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{
+    configuration::{Configuration, FirefightingStrategy},
+    simulation::Cell,
+};
+
+use rand::{distributions::Uniform, rngs::StdRng, Rng};
+
+/// Pojedyncza jednostka straży pożarnej (pozycja w indeksach planszy z obramowaniem).
+pub struct Firefighter {
+    pub x: usize,
+    pub y: usize,
+}
+
+/// Zespół jednostek straży pożarnej.
+pub struct Firefighters {
+    pub units: Vec<Firefighter>,
+
+    /// Liczba kroków symulacji, przez które komórka planszy z obramowaniem pozostanie
+    /// zwilżona.
+    wetness: Vec<u32>,
+
+    /// Łączna liczba ugaszonych komórek.
+    pub extinguished_cells: u64,
+}
+
+impl Firefighters {
+    /// Losowe rozmieszczenie jednostek na planszy.
+    #[inline]
+    pub fn new(rng: &mut StdRng, config: &Configuration) -> Self {
+        let cells_x = (config.resolution.0 / config.cell_size) as usize;
+        let cells_y = (config.resolution.1 / config.cell_size) as usize;
+
+        let distr_x = Uniform::new(1, cells_x + 1);
+        let distr_y = Uniform::new(1, cells_y + 1);
+
+        Self {
+            units: (0..config.firefighter_count)
+                .map(|_| Firefighter {
+                    x: rng.sample(distr_x),
+                    y: rng.sample(distr_y),
+                })
+                .collect(),
+            wetness: vec![0; (cells_x + 2) * (cells_y + 2)],
+            extinguished_cells: 0,
+        }
+    }
+
+    /// Działania jednostek po wygenerowaniu nowego stanu planszy: zwilżone drzewa, które
+    /// zapaliły się w tym kroku, pozostają w stanie sprzed kroku, a następnie jednostki
+    /// przesuwają się w kierunku pożaru, gaszą płonące drzewa w zasięgu działania
+    /// i zwilżają pozostałe.
+    #[inline]
+    pub fn update(&mut self, config: &Configuration, previous_state: &[Cell], state: &mut [Cell]) {
+        if self.units.is_empty() {
+            return;
+        }
+
+        let cells_x = (config.resolution.0 / config.cell_size) as usize + 2;
+        let cells_y = (config.resolution.1 / config.cell_size) as usize + 2;
+
+        for (index, wetness) in self.wetness.iter_mut().enumerate() {
+            if *wetness > 0 {
+                *wetness -= 1;
+
+                if matches!(state[index], Cell::OnFire { .. })
+                    && matches!(
+                        previous_state[index],
+                        Cell::Alive { .. } | Cell::Snag { .. }
+                    )
+                {
+                    state[index] = previous_state[index].clone();
+                }
+            }
+        }
+
+        // Wyznaczenie celów zgodnie ze strategią
+        let targets = find_targets(config, state, cells_x, cells_y);
+
+        for unit in self.units.iter_mut() {
+            let target = match config.firefighter_strategy {
+                // Obrona obszaru: cel najbliższy chronionemu punktowi, bez pożaru
+                // jednostka wraca do chronionego punktu
+                FirefightingStrategy::ProtectArea { x, y } => {
+                    let area = (x as usize + 1, y as usize + 1);
+
+                    nearest(&targets, area).unwrap_or(area)
+                }
+                _ => match nearest(&targets, (unit.x, unit.y)) {
+                    Some(target) => target,
+                    None => continue,
+                },
+            };
+
+            // Przesunięcie jednostki w kierunku celu
+            let speed = config.firefighter_speed as usize;
+            unit.x = approach(unit.x, target.0, speed);
+            unit.y = approach(unit.y, target.1, speed);
+
+            // Gaszenie płonących drzew i zwilżanie pozostałych drzew w zasięgu
+            let radius = config.firefighter_radius as usize;
+            let radius_squared = radius * radius;
+
+            for index_y in unit.y.saturating_sub(radius).max(1)..=(unit.y + radius).min(cells_y - 2)
+            {
+                for index_x in
+                    unit.x.saturating_sub(radius).max(1)..=(unit.x + radius).min(cells_x - 2)
+                {
+                    let distance_squared =
+                        index_x.abs_diff(unit.x).pow(2) + index_y.abs_diff(unit.y).pow(2);
+
+                    if distance_squared <= radius_squared {
+                        let index = index_y * cells_x + index_x;
+
+                        match state[index] {
+                            Cell::OnFire { .. } => {
+                                state[index] = Cell::Dead;
+                                self.extinguished_cells += 1;
+                            }
+                            Cell::Alive { .. } | Cell::Snag { .. } => {
+                                self.wetness[index] = config.firefighter_wetting_steps;
+                            }
+                            Cell::Dead => {}
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Rysowanie znaczników jednostek do bufora klatki.
+    #[inline]
+    pub fn draw(&self, config: &Configuration, frame_buffer: &mut [u8]) {
+        let cell_size = config.cell_size as usize;
+        let width = config.resolution.0 as usize;
+        let height = config.resolution.1 as usize;
+
+        for unit in self.units.iter() {
+            // Znacznik w kształcie krzyża o ramionach długości jednej komórki
+            let center_x = (unit.x - 1) * cell_size + cell_size / 2;
+            let center_y = (unit.y - 1) * cell_size + cell_size / 2;
+            let arm = cell_size + cell_size / 2;
+
+            for offset in 0..=2 * arm {
+                let horizontal = (center_x + offset).checked_sub(arm).map(|x| (x, center_y));
+                let vertical = (center_y + offset).checked_sub(arm).map(|y| (center_x, y));
+
+                for (pixel_x, pixel_y) in horizontal.into_iter().chain(vertical) {
+                    if pixel_x < width && pixel_y < height {
+                        let index = (pixel_y * width + pixel_x) * 3;
+
+                        frame_buffer[index] = config.firefighter_color.0;
+                        frame_buffer[index + 1] = config.firefighter_color.1;
+                        frame_buffer[index + 2] = config.firefighter_color.2;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Wyznaczenie komórek, do których mogą kierować się jednostki.
+#[inline]
+fn find_targets(
+    config: &Configuration,
+    state: &[Cell],
+    cells_x: usize,
+    cells_y: usize,
+) -> Vec<(usize, usize)> {
+    let mut targets = Vec::new();
+
+    for index_y in 1..cells_y - 1 {
+        for index_x in 1..cells_x - 1 {
+            if let Cell::OnFire { .. } = state[index_y * cells_x + index_x] {
+                // Strategia obwodowa uwzględnia jedynie front pożaru
+                let on_perimeter = config.firefighter_strategy != FirefightingStrategy::Perimeter
+                    || (index_y - 1..=index_y + 1).any(|neighbour_y| {
+                        (index_x - 1..=index_x + 1).any(|neighbour_x| {
+                            matches!(
+                                state[neighbour_y * cells_x + neighbour_x],
+                                Cell::Alive { .. } | Cell::Snag { .. }
+                            )
+                        })
+                    });
+
+                if on_perimeter {
+                    targets.push((index_x, index_y));
+                }
+            }
+        }
+    }
+
+    targets
+}
+
+/// Najbliższy (w sensie odległości euklidesowej) cel względem zadanego punktu.
+#[inline]
+fn nearest(targets: &[(usize, usize)], point: (usize, usize)) -> Option<(usize, usize)> {
+    targets
+        .iter()
+        .min_by_key(|target| target.0.abs_diff(point.0).pow(2) + target.1.abs_diff(point.1).pow(2))
+        .copied()
+}
+
+/// Zbliżenie współrzędnej do celu o co najwyżej `speed` komórek.
+#[inline]
+fn approach(position: usize, target: usize, speed: usize) -> usize {
+    if target > position {
+        position + speed.min(target - position)
+    } else {
+        position - speed.min(position - target)
+    }
+}
@@ -0,0 +1,397 @@
+use crate::{
+    configuration::Configuration,
+    error::{ForestFireError, Operation},
+    live::render,
+    locale::text,
+    simulation::Simulation,
+    statistics::Statistics,
+};
+
+use console::{style, Key, Term};
+use serde::{Deserialize, Serialize};
+
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::{Duration, Instant};
+
+/// Liczba linii terminala zarezerwowanych na wiersz stanu i opis klawiszy.
+const STATUS_LINES: usize = 2;
+
+/// Zmiana łatwopalności drzew wywoływana jednym naciśnięciem klawisza.
+const INFLAMMABILITY_STEP: f32 = 0.005;
+
+/// Zmiana szybkości wzrostu drzew wywoływana jednym naciśnięciem klawisza.
+const GROWTH_RATE_STEP: f32 = 0.0005;
+
+/// Zakres mnożnika szybkości symulacji.
+const MIN_SPEED: f64 = 1.0 / 16.0;
+const MAX_SPEED: f64 = 64.0;
+
+/// Zdarzenie wywołane przez użytkownika między krokami symulacji.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum SessionEvent {
+    /// Podpalenie komórki (x, y).
+    Ignite { x: u32, y: u32 },
+
+    /// Usunięcie zawartości komórki (x, y).
+    Clear { x: u32, y: u32 },
+
+    /// Zmiana łatwopalności drzew.
+    SetInflammability(f32),
+
+    /// Zmiana szybkości wzrostu drzew.
+    SetGrowthRate(f32),
+}
+
+/// Dziennik sesji interaktywnej: konfiguracja (wraz z ziarnem generatora) oraz zdarzenia
+/// wraz z numerem kroku, przed którym zostały wykonane.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SessionLog {
+    pub config: Configuration,
+    pub events: Vec<(u32, SessionEvent)>,
+}
+
+/// Wykonanie zdarzenia na symulacji.
+#[inline]
+pub fn apply_event(simulation: &mut Simulation, event: &SessionEvent) {
+    match *event {
+        SessionEvent::Ignite { x, y } => simulation.ignite(x, y),
+        SessionEvent::Clear { x, y } => simulation.clear(x, y),
+        SessionEvent::SetInflammability(value) => simulation.config_mut().inflammability = value,
+        SessionEvent::SetGrowthRate(value) => simulation.config_mut().growth_rate = value,
+    }
+}
+
+/// Wykonanie zdarzenia użytkownika i dopisanie go do dziennika wraz z numerem bieżącego
+/// kroku symulacji.
+#[inline]
+pub fn record_event(
+    simulation: &mut Simulation,
+    events: &mut Vec<(u32, SessionEvent)>,
+    event: SessionEvent,
+) {
+    apply_event(simulation, &event);
+    events.push((simulation.current_statistics().steps, event));
+}
+
+/// Wykonanie zdarzeń z dziennika zaplanowanych przed bieżącym krokiem symulacji.
+#[inline]
+fn apply_scheduled(
+    simulation: &mut Simulation,
+    events: &[(u32, SessionEvent)],
+    next_event: &mut usize,
+) {
+    while let Some((step, event)) = events.get(*next_event) {
+        if *step > simulation.current_statistics().steps {
+            break;
+        }
+
+        apply_event(simulation, event);
+        *next_event += 1;
+    }
+}
+
+/// Wątek odczytujący klawisze (odczyt jest blokujący). Wątek kończy działanie po naciśnięciu
+/// klawisza zakończenia sesji lub Ctrl+C, przywracając zwykły tryb terminala.
+#[inline]
+fn spawn_key_reader() -> Receiver<Key> {
+    let (sender, receiver) = mpsc::channel();
+
+    std::thread::spawn(move || {
+        let term = Term::stdout();
+
+        while let Ok(key) = term.read_key() {
+            let quit = matches!(key, Key::Char('q') | Key::Escape);
+
+            if sender.send(key).is_err() || quit {
+                break;
+            }
+        }
+    });
+
+    receiver
+}
+
+/// Wczytanie dziennika sesji interaktywnej.
+#[inline]
+pub fn load_session_log(path: &str) -> Result<SessionLog, ForestFireError> {
+    let file = File::open(Path::new(path))
+        .map_err(|error| ForestFireError::io(Operation::LoadSessionLog, path, error))?;
+
+    ron::de::from_reader(BufReader::new(file))
+        .map_err(|error| ForestFireError::ron(Operation::LoadSessionLog, Some(path), error))
+}
+
+/// Zapis dziennika sesji interaktywnej.
+#[inline]
+pub fn save_session_log(path: &str, log: &SessionLog) -> Result<(), ForestFireError> {
+    let text = ron::ser::to_string_pretty(log, ron::ser::PrettyConfig::default())
+        .map_err(|error| ForestFireError::encoding(Operation::SaveSessionLog, path, error))?;
+
+    std::fs::write(Path::new(path), text)
+        .map_err(|error| ForestFireError::io(Operation::SaveSessionLog, path, error))
+}
+
+/// Sesja interaktywna: podgląd symulacji w terminalu ze sterowaniem z klawiatury. Zdarzenia
+/// wywołane przez użytkownika są zapisywane do dziennika `log_path`, co pozwala powtórzyć
+/// sesję (podkomenda `replay`).
+#[inline]
+pub fn interactive(config: &Configuration, log_path: &str) -> Result<Statistics, ForestFireError> {
+    let mut log = SessionLog {
+        config: config.effective(),
+        events: Vec::new(),
+    };
+
+    let statistics = run_session(config, &mut log.events, false)?;
+
+    save_session_log(log_path, &log)?;
+    println!(
+        "{} {}",
+        style(text("Saved the session log:", "Zapisano dziennik sesji:"))
+            .green()
+            .bold(),
+        style(format!("\"{}\"", log_path)).cyan().italic().bold()
+    );
+
+    Ok(statistics)
+}
+
+/// Powtórzenie sesji interaktywnej na podstawie dziennika bez podglądu (zdarzenia są
+/// wykonywane przed tymi samymi krokami symulacji, co w sesji oryginalnej, a zdarzenia
+/// zapisane po ostatnim kroku są wykonywane na końcu).
+#[inline]
+pub fn replay(
+    config: &Configuration,
+    events: &[(u32, SessionEvent)],
+) -> Result<Statistics, ForestFireError> {
+    let mut simulation = Simulation::new(config)?;
+    let last_step = config.total_steps();
+    let mut next_event = 0;
+
+    while simulation.current_statistics().steps < last_step {
+        apply_scheduled(&mut simulation, events, &mut next_event);
+        simulation.step();
+    }
+
+    apply_scheduled(&mut simulation, events, &mut next_event);
+
+    Ok(simulation.statistics())
+}
+
+/// Powtórzenie sesji interaktywnej na podstawie dziennika z podglądem w terminalu. Podgląd
+/// kończy się po ostatnim kroku symulacji (lub po naciśnięciu klawisza zakończenia sesji).
+#[inline]
+pub fn watch_replay(
+    config: &Configuration,
+    mut events: Vec<(u32, SessionEvent)>,
+) -> Result<Statistics, ForestFireError> {
+    run_session(config, &mut events, true)
+}
+
+/// Główna pętla sesji. W trybie powtórki zdarzenia z listy `events` są wykonywane przed
+/// krokami o zapisanych numerach, a edycja planszy jest wyłączona; w przeciwnym razie
+/// zdarzenia użytkownika są dopisywane do listy.
+#[inline]
+fn run_session(
+    config: &Configuration,
+    events: &mut Vec<(u32, SessionEvent)>,
+    replay: bool,
+) -> Result<Statistics, ForestFireError> {
+    let mut simulation = Simulation::new(config)?;
+
+    let cells_x = config.resolution.0 / config.cell_size;
+    let cells_y = config.resolution.1 / config.cell_size;
+
+    let mut frame_buffer: Vec<u8> =
+        vec![0; (config.resolution.0 * config.resolution.1 * 3) as usize];
+
+    let frame_duration = Duration::from_secs_f64(1.0 / config.frame_rate as f64);
+    let last_step = config.total_steps();
+
+    let mut paused = false;
+    let mut single_step = false;
+    let mut speed = 1.0_f64;
+    let mut cursor = (cells_x / 2, cells_y / 2);
+    let mut next_event = 0;
+
+    let keys = spawn_key_reader();
+
+    let term = Term::stdout();
+    term.hide_cursor().unwrap();
+    term.clear_screen().unwrap();
+
+    'session: loop {
+        let frame_start = Instant::now();
+        let (rows, columns) = term.size();
+
+        // Przesunięcie kursora o jeden znak terminala
+        let cursor_step = (cells_x as f32 / columns.max(1) as f32).ceil().max(1.0) as u32;
+
+        // Obsługa klawiszy
+        loop {
+            let key = match keys.try_recv() {
+                Ok(key) => key,
+                Err(TryRecvError::Empty) => break,
+                // Powtórka nie wymaga klawiatury (np. gdy wejście nie jest terminalem)
+                Err(TryRecvError::Disconnected) if replay => break,
+                Err(TryRecvError::Disconnected) => break 'session,
+            };
+
+            let mut event = None;
+
+            match key {
+                Key::Char('q') | Key::Escape => break 'session,
+                Key::Char(' ') => paused = !paused,
+                Key::Char('n') | Key::Enter => {
+                    paused = true;
+                    single_step = true;
+                }
+                Key::Char('+') => speed = (speed * 2.0).min(MAX_SPEED),
+                Key::Char('-') => speed = (speed / 2.0).max(MIN_SPEED),
+                Key::ArrowLeft => cursor.0 = cursor.0.saturating_sub(cursor_step),
+                Key::ArrowRight => cursor.0 = (cursor.0 + cursor_step).min(cells_x - 1),
+                Key::ArrowUp => cursor.1 = cursor.1.saturating_sub(cursor_step),
+                Key::ArrowDown => cursor.1 = (cursor.1 + cursor_step).min(cells_y - 1),
+                Key::Char('f') => {
+                    event = Some(SessionEvent::Ignite {
+                        x: cursor.0,
+                        y: cursor.1,
+                    })
+                }
+                Key::Char('c') => {
+                    event = Some(SessionEvent::Clear {
+                        x: cursor.0,
+                        y: cursor.1,
+                    })
+                }
+                Key::Char(key @ ('i' | 'I')) => {
+                    let change = if key == 'I' { 1.0 } else { -1.0 } * INFLAMMABILITY_STEP;
+                    let value = (simulation.config().inflammability + change).clamp(0.0, 1.0);
+                    event = Some(SessionEvent::SetInflammability(value));
+                }
+                Key::Char(key @ ('g' | 'G')) => {
+                    let change = if key == 'G' { 1.0 } else { -1.0 } * GROWTH_RATE_STEP;
+                    let value = (simulation.config().growth_rate + change).clamp(0.0, 1.0);
+                    event = Some(SessionEvent::SetGrowthRate(value));
+                }
+                _ => {}
+            }
+
+            // Edycja jest wyłączona podczas powtórki sesji
+            if let Some(event) = event.filter(|_| !replay) {
+                record_event(&mut simulation, events, event);
+            }
+        }
+
+        // Kroki symulacji (zdarzenia zapisane po ostatnim kroku są wykonywane na końcu)
+        let finished = simulation.current_statistics().steps >= last_step;
+
+        if replay && finished {
+            apply_scheduled(&mut simulation, events, &mut next_event);
+        }
+
+        if (!paused || single_step) && !finished {
+            let steps = if speed >= 1.0 {
+                config.steps_per_frame * speed as u32
+            } else {
+                config.steps_per_frame
+            };
+
+            for _ in 0..steps {
+                if replay {
+                    apply_scheduled(&mut simulation, events, &mut next_event);
+                }
+
+                if simulation.current_statistics().steps >= last_step {
+                    break;
+                }
+
+                simulation.step();
+            }
+
+            single_step = false;
+        }
+
+        // Rysowanie klatki wraz z wierszem stanu
+        simulation.draw(&mut frame_buffer);
+
+        let mut output = render(
+            config,
+            &frame_buffer,
+            columns as usize,
+            (rows as usize).saturating_sub(STATUS_LINES),
+            (!replay).then_some(cursor),
+        );
+
+        let statistics = simulation.current_statistics();
+        let state = if finished {
+            style(text("Finished", "Koniec")).red().bold()
+        } else if paused {
+            style(text("Paused", "Pauza")).yellow().bold()
+        } else {
+            style(text("Running", "Symulacja")).green().bold()
+        };
+
+        write!(
+            output,
+            "{} {} {}/{}  {} {}x  {} {}  {} {}  {} {:.4}  {} {:.4}  {} ({}, {})\x1b[K\r\n",
+            state,
+            style(text("Step:", "Krok:")).green().bold(),
+            statistics.steps,
+            last_step,
+            style(text("Speed:", "Szybkość:")).green().bold(),
+            speed,
+            style(text("Alive:", "Żywe:")).green().bold(),
+            statistics.alive_cells,
+            style(text("Burning:", "Płonące:")).red().bold(),
+            statistics.on_fire_cells,
+            style("inflammability:").yellow(),
+            simulation.config().inflammability,
+            style("growth_rate:").yellow(),
+            simulation.config().growth_rate,
+            style(text("Cursor:", "Kursor:")).green().bold(),
+            cursor.0,
+            cursor.1
+        )
+        .unwrap();
+
+        write!(
+            output,
+            "{}\x1b[K",
+            style(text(
+                "space: pause  n: step  +/-: speed  arrows: cursor  f: ignite  \
+                c: clear  i/I, g/G: parameters  q: quit",
+                "spacja: pauza  n: krok  +/-: szybkość  strzałki: kursor  f: podpalenie  \
+                c: usunięcie  i/I, g/G: parametry  q: koniec"
+            ))
+            .dim()
+        )
+        .unwrap();
+
+        term.move_cursor_to(0, 0).unwrap();
+        term.write_str(&output).unwrap();
+
+        // Utrzymanie zadanej liczby klatek na sekundę (z uwzględnieniem szybkości)
+        let frame_duration = if speed >= 1.0 {
+            frame_duration
+        } else {
+            frame_duration.div_f64(speed)
+        };
+
+        if replay && finished {
+            break;
+        }
+
+        if let Some(remaining) = frame_duration.checked_sub(frame_start.elapsed()) {
+            std::thread::sleep(remaining);
+        }
+    }
+
+    term.clear_screen().unwrap();
+    term.show_cursor().unwrap();
+
+    Ok(simulation.statistics())
+}
@@ -0,0 +1,39 @@
+//! Model pożaru lasu (automat komórkowy) wraz z wizualizacją.
+//!
+//! Rdzeń symulacji (konfiguracja, symulacja, statystyki i rysowanie klatek) nie korzysta
+//! z terminala ani z plików, dzięki czemu może być kompilowany również do WebAssembly.
+//! Program wiersza poleceń oraz kodery animacji wymagają cechy `cli` (domyślnie włączonej),
+//! interfejs dla JavaScriptu cechy `wasm`, moduł rozszerzenia Pythona cechy `python`,
+//! a interfejs C cechy `capi`.
+
+pub mod assets;
+pub mod configuration;
+pub mod error;
+pub mod firefighting;
+pub mod locale;
+pub mod management;
+pub mod presets;
+pub mod simulation;
+pub mod specification;
+pub mod statistics;
+pub mod visualization;
+
+#[cfg(feature = "cli")]
+pub mod ensemble;
+#[cfg(feature = "cli")]
+pub mod interactive;
+#[cfg(feature = "cli")]
+pub mod live;
+#[cfg(feature = "cli")]
+pub mod output;
+#[cfg(feature = "cli")]
+pub mod server;
+#[cfg(feature = "cli")]
+pub mod sweep;
+
+#[cfg(feature = "capi")]
+pub mod capi;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "wasm")]
+pub mod wasm;
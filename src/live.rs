@@ -0,0 +1,163 @@
+use crate::{
+    configuration::Configuration, error::ForestFireError, locale::text, simulation::Simulation,
+    statistics::Statistics,
+};
+
+use console::{style, Term};
+
+use std::fmt::Write as _;
+use std::time::{Duration, Instant};
+
+/// Liczba linii terminala zarezerwowanych na wiersz stanu.
+const STATUS_LINES: usize = 1;
+
+/// Kolor kursora.
+const CURSOR_COLOR: (u8, u8, u8) = (255, 0, 255);
+
+/// Rysowanie klatki w terminalu: każdy znak `▀` reprezentuje dwa piksele w pionie (kolor
+/// tekstu to piksel górny, kolor tła to piksel dolny). Obraz jest skalowany do rozmiaru
+/// terminala z zachowaniem proporcji. Opcjonalny kursor wskazuje komórkę (x, y).
+/// Wiersze kończą się znakami `\r\n`, aby obraz był poprawny również w trybie surowym
+/// terminala.
+#[inline]
+pub fn render(
+    config: &Configuration,
+    frame_buffer: &[u8],
+    columns: usize,
+    rows: usize,
+    cursor: Option<(u32, u32)>,
+) -> String {
+    let (width, height) = (config.resolution.0 as usize, config.resolution.1 as usize);
+
+    // Skala obrazu dopasowana do terminala (wiersz terminala to dwa piksele w pionie)
+    let scale = (columns as f32 / width as f32).min((rows * 2) as f32 / height as f32);
+    let output_width = ((width as f32 * scale) as usize).clamp(1, columns.max(1));
+    let output_height = ((height as f32 * scale) as usize / 2).clamp(1, rows.max(1));
+
+    let pixel = |x: usize, y: usize| {
+        let source_x = (x * width / output_width).min(width - 1);
+        let source_y = (y * height / (output_height * 2)).min(height - 1);
+        let index = (source_y * width + source_x) * 3;
+
+        (
+            frame_buffer[index],
+            frame_buffer[index + 1],
+            frame_buffer[index + 2],
+        )
+    };
+
+    // Pozycja kursora w znakach terminala (środek komórki)
+    let cursor = cursor.map(|(x, y)| {
+        let center_x = ((x * config.cell_size + config.cell_size / 2) as usize).min(width - 1);
+        let center_y = ((y * config.cell_size + config.cell_size / 2) as usize).min(height - 1);
+
+        (
+            center_x * output_width / width,
+            center_y * output_height * 2 / height,
+        )
+    });
+
+    let mut output = String::with_capacity(output_width * output_height * 40);
+
+    for row in 0..output_height {
+        for x in 0..output_width {
+            let mut upper = pixel(x, row * 2);
+            let mut lower = pixel(x, row * 2 + 1);
+
+            if let Some((cursor_x, cursor_y)) = cursor {
+                if cursor_x == x && cursor_y / 2 == row {
+                    if cursor_y % 2 == 0 {
+                        upper = CURSOR_COLOR;
+                    } else {
+                        lower = CURSOR_COLOR;
+                    }
+                }
+            }
+
+            write!(
+                output,
+                "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m▀",
+                upper.0, upper.1, upper.2, lower.0, lower.1, lower.2
+            )
+            .unwrap();
+        }
+
+        output.push_str("\x1b[0m\x1b[K\r\n");
+    }
+
+    output
+}
+
+/// Podgląd symulacji na żywo w terminalu (bez zapisu animacji). Klatki są odświeżane
+/// z częstotliwością `frame_rate`, a pod obrazem wyświetlany jest wiersz stanu.
+#[inline]
+pub fn live(config: &Configuration) -> Result<Statistics, ForestFireError> {
+    let mut simulation = Simulation::new(config)?;
+
+    let mut frame_buffer: Vec<u8> =
+        vec![0; (config.resolution.0 * config.resolution.1 * 3) as usize];
+
+    let frame_duration = Duration::from_secs_f64(1.0 / config.frame_rate as f64);
+
+    let term = Term::stdout();
+    term.hide_cursor().unwrap();
+    term.clear_screen().unwrap();
+
+    // Rozgrzewka symulacji (bez podglądu)
+    for _ in 0..config.warmup_steps {
+        simulation.step();
+    }
+
+    for frame_number in 0..config.frames {
+        let frame_start = Instant::now();
+
+        for _ in 0..config.steps_per_frame {
+            simulation.step();
+        }
+
+        simulation.draw(&mut frame_buffer);
+
+        // Dopasowanie obrazu do bieżącego rozmiaru terminala
+        let (rows, columns) = term.size();
+        let mut output = render(
+            config,
+            &frame_buffer,
+            columns as usize,
+            (rows as usize).saturating_sub(STATUS_LINES),
+            None,
+        );
+
+        let statistics = simulation.current_statistics();
+        write!(
+            output,
+            "{} {}/{}  {} {}  {} {}  {} {}  {} {}  {} {}\x1b[K",
+            style(text("Frame:", "Klatka:")).green().bold(),
+            frame_number + 1,
+            config.frames,
+            style(text("Step:", "Krok:")).green().bold(),
+            statistics.steps,
+            style(text("Alive:", "Żywe:")).green().bold(),
+            statistics.alive_cells,
+            style(text("Burning:", "Płonące:")).red().bold(),
+            statistics.on_fire_cells,
+            style(text("Snags:", "Posusz:")).yellow().bold(),
+            statistics.snag_cells,
+            style(text("Dead:", "Martwe:")).bold(),
+            statistics.dead_cells
+        )
+        .unwrap();
+
+        term.move_cursor_to(0, 0).unwrap();
+        term.write_str(&output).unwrap();
+
+        // Utrzymanie zadanej liczby klatek na sekundę
+        if let Some(remaining) = frame_duration.checked_sub(frame_start.elapsed()) {
+            std::thread::sleep(remaining);
+        }
+    }
+
+    println!();
+    term.show_cursor().unwrap();
+
+    Ok(simulation.statistics())
+}
@@ -0,0 +1,82 @@
+//! Język komunikatów (angielski lub polski). Język jest ustawiany raz dla całego procesu,
+//! domyślnie jest to angielski.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Aktualnie wybrany język komunikatów.
+static LANGUAGE: AtomicU8 = AtomicU8::new(Language::English as u8);
+
+/// Język komunikatów.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Language {
+    /// Angielski (domyślny).
+    #[cfg_attr(feature = "cli", value(name = "en", help = text("English", "angielski")))]
+    English,
+
+    /// Polski.
+    #[cfg_attr(feature = "cli", value(name = "pl", help = text("Polish", "polski")))]
+    Polish,
+}
+
+impl Language {
+    /// Wykrycie języka na podstawie zmiennych środowiskowych `LC_ALL`, `LC_MESSAGES`
+    /// i `LANG` (np. `pl_PL.UTF-8`). Dla nieobsługiwanych języków wybierany jest angielski.
+    #[inline]
+    pub fn detect() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Self::from_code(&value))
+            .unwrap_or(Self::English)
+    }
+
+    /// Język o podanym kodzie (`en`, `pl`, `pl_PL.UTF-8`, ...).
+    #[inline]
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.split(['_', '-', '.']).next()?.to_lowercase();
+
+        match code.as_str() {
+            "en" | "c" | "posix" => Some(Self::English),
+            "pl" => Some(Self::Polish),
+            _ => None,
+        }
+    }
+}
+
+/// Ustawienie języka komunikatów.
+#[inline]
+pub fn set_language(language: Language) {
+    LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+/// Aktualnie wybrany język komunikatów.
+#[inline]
+pub fn language() -> Language {
+    match LANGUAGE.load(Ordering::Relaxed) {
+        value if value == Language::Polish as u8 => Language::Polish,
+        _ => Language::English,
+    }
+}
+
+/// Wybór tekstu w aktualnym języku.
+#[inline]
+pub fn text(english: &'static str, polish: &'static str) -> &'static str {
+    match language() {
+        Language::English => english,
+        Language::Polish => polish,
+    }
+}
+
+/// Sformatowanie komunikatu w aktualnym języku, np.
+/// `tr!("Frame {}", "Klatka {}", number)`.
+#[macro_export]
+macro_rules! tr {
+    ($english:literal, $polish:literal $(, $argument:expr)* $(,)?) => {
+        match $crate::locale::language() {
+            $crate::locale::Language::English => format!($english $(, $argument)*),
+            $crate::locale::Language::Polish => format!($polish $(, $argument)*),
+        }
+    };
+}
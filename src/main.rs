@@ -1,44 +1,333 @@
-mod configuration;
-mod simulation;
-mod visualization;
-
-fn main() {
-    // Pobranie nazwy ścieżki pliku konfiguracyjnego z prametrów wywołania
-    let config_path = match configuration::get_configuration_file() {
-        Ok(path) => path,
-        Err(error) => {
-            println!("{}", error);
-            return;
+mod cli;
+
+use cli::Command;
+use forest_fire::configuration::{self, Configuration};
+use forest_fire::error::ForestFireError;
+use forest_fire::locale::{self, text, Language};
+use forest_fire::{ensemble, interactive, live, server, simulation, statistics, sweep, tr};
+
+use rand::{thread_rng, Rng};
+
+use std::process::ExitCode;
+
+/// Wynik podkomendy: `Err(())` oznacza błąd, który został już wypisany.
+type Outcome = Result<(), ()>;
+
+fn main() -> ExitCode {
+    // Parsowanie parametrów wywołania
+    let cli = cli::parse_arguments();
+
+    // Wybór języka komunikatów
+    locale::set_language(cli.lang.unwrap_or_else(Language::detect));
+
+    let outcome = match cli.command {
+        Command::Run(arguments) => prepare_configuration(&arguments)
+            .and_then(|config| run(&config, &arguments.config_path, RunMode::Animation)),
+        Command::Validate(arguments) => prepare_configuration(&arguments).map(|config| {
+            configuration::print_configuration(&config, &arguments.config_path);
+            println!(
+                "\n{}",
+                console::style(text(
+                    "The configuration is valid.",
+                    "Konfiguracja jest poprawna.",
+                ))
+                .green()
+                .bold()
+            );
+        }),
+        Command::PrintSpec(arguments) => match &arguments.schema {
+            Some(path) => configuration::save_configuration_schema(path)
+                .map(|()| {
+                    println!(
+                        "{} {}",
+                        console::style(text(
+                            "Saved the configuration schema:",
+                            "Zapisano schemat konfiguracji:"
+                        ))
+                        .green()
+                        .bold(),
+                        console::style(format!("\"{}\"", path))
+                            .cyan()
+                            .italic()
+                            .bold()
+                    )
+                })
+                .map_err(|error| report(&error)),
+            None => {
+                configuration::print_configuration_specification();
+                Ok(())
+            }
+        },
+        Command::Convert(arguments) => convert(&arguments.input_path, &arguments.output_path),
+        Command::Stats(arguments) => prepare_configuration(&arguments)
+            .and_then(|config| run(&config, &arguments.config_path, RunMode::Statistics)),
+        Command::Live(arguments) => prepare_configuration(&arguments)
+            .and_then(|config| run(&config, &arguments.config_path, RunMode::Live)),
+        Command::Interactive(arguments) => prepare_configuration(&arguments.configuration)
+            .and_then(|config| {
+                run(
+                    &config,
+                    &arguments.configuration.config_path,
+                    RunMode::Interactive(arguments.log),
+                )
+            }),
+        Command::Replay(arguments) => interactive::load_session_log(&arguments.log_path)
+            .and_then(|log| {
+                configuration::validate_configuration(&log.config)?;
+                Ok(log)
+            })
+            .map_err(|error| report(&error))
+            .and_then(|log| {
+                run(
+                    &log.config,
+                    &arguments.log_path,
+                    RunMode::Replay {
+                        events: log.events,
+                        watch: arguments.watch,
+                    },
+                )
+            }),
+        Command::Serve(arguments) => {
+            prepare_configuration(&arguments.configuration).and_then(|config| {
+                configuration::print_configuration(&config, &arguments.configuration.config_path);
+                server::serve(&config, &arguments.address).map_err(|error| report(&error))
+            })
+        }
+        Command::Sweep(arguments) => {
+            prepare_configuration(&arguments.configuration).and_then(|config| {
+                let jobs = arguments.jobs.unwrap_or_else(|| {
+                    std::thread::available_parallelism().map_or(1, |jobs| jobs.get())
+                });
+
+                sweep::sweep(
+                    &config,
+                    &arguments.parameters,
+                    arguments.samples,
+                    jobs,
+                    &arguments.summary,
+                )
+                .map_err(|error| report(&error))?;
+
+                save_effective_configuration(&config, &arguments.summary)
+            })
+        }
+        Command::Ensemble(arguments) => {
+            prepare_configuration(&arguments.configuration).and_then(|config| {
+                let jobs = arguments.jobs.unwrap_or_else(|| {
+                    std::thread::available_parallelism().map_or(1, |jobs| jobs.get())
+                });
+
+                ensemble::ensemble(
+                    &config,
+                    arguments.replicas,
+                    jobs,
+                    &arguments.heatmap,
+                    &arguments.grid,
+                    &arguments.summary,
+                )
+                .map_err(|error| report(&error))?;
+
+                save_effective_configuration(&config, &arguments.heatmap)?;
+                save_effective_configuration(&config, &arguments.grid)?;
+                save_effective_configuration(&config, &arguments.summary)
+            })
         }
     };
 
+    match outcome {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(()) => ExitCode::FAILURE,
+    }
+}
+
+/// Wypisanie opisu błędu na standardowe wyjście błędów.
+fn report(error: &ForestFireError) {
+    eprintln!("{}", cli::render_error(error));
+}
+
+/// Załadowanie, nadpisanie i walidacja konfiguracji oraz (opcjonalnie) zapis konfiguracji
+/// efektywnej do pliku podanego w opcji `--dump-config`. W przypadku błędu jest on
+/// wypisywany, a funkcja zwraca `Err(())`.
+fn prepare_configuration(arguments: &cli::ConfigurationArgs) -> Result<Configuration, ()> {
     // Załadowanie konfiguracji z pliku
-    let config = match configuration::load_configuration(&config_path) {
+    let config = match configuration::load_configuration(&arguments.config_path) {
         Ok(config) => config,
         Err(error) => {
-            println!("{}", error);
+            report(&error);
             configuration::print_configuration_specification();
-            return;
+            return Err(());
         }
     };
 
-    // Walidacja konfiguracji
-    match configuration::validate_configuration(&config) {
+    // Nadpisanie parametrów podanych w wywołaniu programu
+    let overrides = arguments.all_overrides();
+    let mut config = match configuration::override_configuration(&config, &overrides) {
+        Ok(config) => config,
         Err(error) => {
-            println!("{}", error);
-            return;
+            report(&error);
+            return Err(());
         }
-        _ => {}
+    };
+
+    // Walidacja konfiguracji
+    if let Err(error) = configuration::validate_configuration(&config) {
+        report(&error);
+        return Err(());
+    }
+
+    // Konfiguracja zapisana przez inną wersję programu może dawać inne wyniki
+    if let Some(version) = config
+        .version
+        .as_deref()
+        .filter(|version| *version != configuration::VERSION)
+    {
+        println!(
+            "{}",
+            console::style(tr!(
+                "Warning: the configuration was written by version {} of the program \
+                    (current version: {}), the results may differ.",
+                "Uwaga: konfiguracja została zapisana przez wersję {} programu \
+                    (bieżąca wersja: {}), wyniki mogą się różnić.",
+                version,
+                configuration::VERSION
+            ))
+            .yellow()
+        );
     }
 
+    // Ustalenie ziarna generatora (umożliwia powtórzenie symulacji). Zakres ziarna pozwala
+    // zapisać konfigurację efektywną także w formacie TOML (liczby całkowite ze znakiem)
+    config
+        .seed
+        .get_or_insert_with(|| thread_rng().gen_range(0..=i64::MAX as u64));
+
+    // Zapis konfiguracji efektywnej
+    if let Some(dump_path) = &arguments.dump_config {
+        if let Err(error) = configuration::save_effective_configuration(&config, dump_path) {
+            report(&error);
+            return Err(());
+        }
+
+        print_saved_effective_configuration(dump_path);
+    }
+
+    Ok(config)
+}
+
+/// Zapis konfiguracji efektywnej obok pliku wynikowego `output_path`, pozwalający dokładnie
+/// powtórzyć symulację.
+fn save_effective_configuration(config: &Configuration, output_path: &str) -> Outcome {
+    let path = configuration::effective_configuration_path(output_path);
+
+    configuration::save_effective_configuration(config, &path)
+        .map(|()| print_saved_effective_configuration(&path))
+        .map_err(|error| report(&error))
+}
+
+/// Komunikat o zapisaniu konfiguracji efektywnej.
+fn print_saved_effective_configuration(path: &str) {
+    println!(
+        "{} {}",
+        console::style(text(
+            "Saved the effective configuration:",
+            "Zapisano konfigurację efektywną:"
+        ))
+        .green()
+        .bold(),
+        console::style(format!("\"{}\"", path))
+            .cyan()
+            .italic()
+            .bold()
+    );
+}
+
+/// Konwersja pliku konfiguracyjnego do formatu ustalonego na podstawie rozszerzenia pliku
+/// wynikowego. Zapisywana konfiguracja zawiera wartości domyślne pominiętych parametrów.
+fn convert(input_path: &str, output_path: &str) -> Outcome {
+    let result = configuration::load_configuration(input_path).and_then(|config| {
+        configuration::validate_configuration(&config)?;
+        configuration::save_configuration(&config, output_path)
+    });
+
+    result
+        .map(|()| {
+            println!(
+                "{} {}",
+                console::style(text("Saved the configuration:", "Zapisano konfigurację:"))
+                    .green()
+                    .bold(),
+                console::style(format!("\"{}\"", output_path))
+                    .cyan()
+                    .italic()
+                    .bold()
+            )
+        })
+        .map_err(|error| report(&error))
+}
+
+/// Sposób przeprowadzenia symulacji.
+enum RunMode {
+    /// Symulacja z zapisem animacji.
+    Animation,
+
+    /// Symulacja bez zapisu animacji (jedynie statystyki).
+    Statistics,
+
+    /// Symulacja z podglądem na żywo w terminalu.
+    Live,
+
+    /// Sesja interaktywna z zapisem dziennika sesji do podanego pliku.
+    Interactive(String),
+
+    /// Powtórzenie sesji interaktywnej na podstawie dziennika (z podglądem w terminalu lub
+    /// jedynie ze statystykami).
+    Replay {
+        events: Vec<(u32, interactive::SessionEvent)>,
+        watch: bool,
+    },
+}
+
+/// Przeprowadzenie symulacji (z zapisem animacji, podglądem w terminalu lub jedynie
+/// ze statystykami).
+fn run(config: &Configuration, config_path: &str, mode: RunMode) -> Outcome {
     // Wypisanie wartości parametrów
-    configuration::print_configuration(&config, &config_path);
+    configuration::print_configuration(config, config_path);
+
+    let animation = matches!(mode, RunMode::Animation);
 
     // Przeprowadzenie symulacji
-    match simulation::simulate(&config) {
-        Err(error) => {
-            println!("{}", error);
-        }
-        _ => {}
+    let result = match mode {
+        RunMode::Animation => simulation::simulate(config),
+        RunMode::Statistics => simulation::simulate_without_output(
+            config,
+            text("Simulation progress:", "Postęp symulacji:"),
+        ),
+        RunMode::Live => live::live(config),
+        RunMode::Interactive(log_path) => interactive::interactive(config, &log_path),
+        RunMode::Replay {
+            events,
+            watch: true,
+        } => interactive::watch_replay(config, events),
+        RunMode::Replay {
+            events,
+            watch: false,
+        } => interactive::replay(config, &events),
+    };
+
+    let statistics = result.map_err(|error| report(&error))?;
+
+    statistics::print_statistics(&statistics);
+
+    // Konfiguracja efektywna obok zapisanej animacji
+    if animation {
+        save_effective_configuration(config, &config.output_path)?;
     }
+
+    // Porównanie z symulacją bez zarządzania lasem
+    if config.management_interval > 0 && config.management_baseline {
+        let unmanaged = simulation::simulate_unmanaged(config).map_err(|error| report(&error))?;
+        statistics::print_management_comparison(&statistics, &unmanaged);
+    }
+
+    Ok(())
 }
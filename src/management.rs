@@ -0,0 +1,177 @@
+use crate::{
+    configuration::{Configuration, ManagementAction, ManagementStrategy},
+    simulation::Cell,
+    statistics::Statistics,
+};
+
+use rand::{rngs::StdRng, seq::SliceRandom};
+
+/// Maksymalny rozmiar drzewa podpalanego podczas wypalania kontrolowanego (większe drzewa
+/// mogą zapalić się jedynie od płonących sąsiadów).
+const CONTROLLED_BURN_MAX_TREE_SIZE: f32 = 0.5;
+
+/// Blok komórek (fragment drzewostanu) podlegający zabiegom.
+struct Block {
+    /// Indeksy komórek bloku na planszy z obramowaniem (jedynie komórki leżące
+    /// w obszarach zarządzanych).
+    cells: Vec<usize>,
+}
+
+/// Warstwa zarządzania lasem: okresowe wypalanie kontrolowane lub wycinka.
+pub struct Management {
+    blocks: Vec<Block>,
+
+    /// Pozycja w cyklu zabiegów dla strategii siatki.
+    cursor: usize,
+
+    /// Łączna liczba komórek, z których usunięto (lub podpalono) drzewa.
+    pub treated_cells: u64,
+}
+
+impl Management {
+    /// Podział planszy na bloki o boku `management_patch_size` ograniczone do obszarów
+    /// zarządzanych.
+    #[inline]
+    pub fn new(config: &Configuration) -> Self {
+        let cells_x = config.resolution.0 / config.cell_size;
+        let cells_y = config.resolution.1 / config.cell_size;
+        let patch_size = config.management_patch_size;
+
+        let managed = |x: u32, y: u32| {
+            config.management_regions.is_empty()
+                || config
+                    .management_regions
+                    .iter()
+                    .any(|&(region_x, region_y, width, height)| {
+                        x >= region_x
+                            && x < region_x + width
+                            && y >= region_y
+                            && y < region_y + height
+                    })
+        };
+
+        let mut blocks = Vec::new();
+
+        for block_y in (0..cells_y).step_by(patch_size as usize) {
+            for block_x in (0..cells_x).step_by(patch_size as usize) {
+                let mut cells = Vec::new();
+
+                for index_y in block_y..(block_y + patch_size).min(cells_y) {
+                    for index_x in block_x..(block_x + patch_size).min(cells_x) {
+                        if managed(index_x, index_y) {
+                            cells.push(((index_y + 1) * (cells_x + 2) + index_x + 1) as usize);
+                        }
+                    }
+                }
+
+                if !cells.is_empty() {
+                    blocks.push(Block { cells });
+                }
+            }
+        }
+
+        Self {
+            blocks,
+            cursor: 0,
+            treated_cells: 0,
+        }
+    }
+
+    /// Wykonanie zabiegów (między krokami symulacji, po aktualizacji statystyk), jeżeli
+    /// nadszedł ich termin. Ogień wypalania kontrolowanego rozprzestrzenia się w kolejnych
+    /// krokach symulacji, a każdy wypalany blok jest rejestrowany w statystykach jako
+    /// osobny pożar.
+    #[inline]
+    pub fn update(
+        &mut self,
+        rng: &mut StdRng,
+        config: &Configuration,
+        state: &mut [Cell],
+        statistics: &mut Statistics,
+    ) {
+        if config.management_interval == 0
+            || !statistics.steps.is_multiple_of(config.management_interval)
+            || self.blocks.is_empty()
+        {
+            return;
+        }
+
+        // Liczba bloków objętych zabiegiem
+        let count = ((config.management_fraction * self.blocks.len() as f32).ceil() as usize)
+            .min(self.blocks.len());
+
+        let selected: Vec<usize> = match config.management_strategy {
+            // Losowo wybrane bloki
+            ManagementStrategy::RandomPatches => {
+                let mut indices: Vec<usize> = (0..self.blocks.len()).collect();
+                indices.shuffle(rng);
+                indices.truncate(count);
+                indices
+            }
+            // Kolejne bloki siatki, cyklicznie
+            ManagementStrategy::GridPattern => {
+                let indices = (0..count)
+                    .map(|offset| (self.cursor + offset) % self.blocks.len())
+                    .collect();
+                self.cursor = (self.cursor + count) % self.blocks.len();
+                indices
+            }
+            // Bloki o największym średnim wieku drzew
+            ManagementStrategy::OldestFirst => {
+                let mean_ages: Vec<f32> = self
+                    .blocks
+                    .iter()
+                    .map(|block| {
+                        block
+                            .cells
+                            .iter()
+                            .map(|&index| match state[index] {
+                                Cell::Alive { age, .. } => age as f32,
+                                _ => 0.0,
+                            })
+                            .sum::<f32>()
+                            / block.cells.len() as f32
+                    })
+                    .collect();
+
+                let mut indices: Vec<usize> = (0..self.blocks.len()).collect();
+                indices.sort_by(|&a, &b| mean_ages[b].total_cmp(&mean_ages[a]));
+                indices.truncate(count);
+                indices
+            }
+        };
+
+        for block_index in selected {
+            let mut ignited = Vec::new();
+
+            for &index in self.blocks[block_index].cells.iter() {
+                match (config.management_action, &state[index]) {
+                    // Wycinka usuwa wszystkie drzewa
+                    (ManagementAction::ClearCut, Cell::Alive { .. } | Cell::Snag { .. }) => {
+                        state[index] = Cell::Dead;
+                    }
+                    // Wypalanie kontrolowane podpala posusz i młode drzewa
+                    (ManagementAction::ControlledBurn, Cell::Snag { .. }) => {
+                        ignited.push(index);
+                    }
+                    (ManagementAction::ControlledBurn, Cell::Alive { size, .. })
+                        if *size <= CONTROLLED_BURN_MAX_TREE_SIZE =>
+                    {
+                        ignited.push(index);
+                    }
+                    _ => continue,
+                }
+
+                self.treated_cells += 1;
+            }
+
+            for &index in ignited.iter() {
+                state[index] = Cell::OnFire { progress: 1.0 };
+            }
+
+            if !ignited.is_empty() {
+                statistics.register_controlled_burn(&ignited);
+            }
+        }
+    }
+}
@@ -0,0 +1,691 @@
+use crate::{
+    configuration::{Configuration, OutputFormat},
+    error::{ForestFireError, Operation},
+    locale::text,
+    tr, visualization,
+};
+
+use image::{codecs::webp::WebPEncoder, ColorType};
+
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::thread::JoinHandle;
+
+/// Domyślne polecenie zewnętrznego kodera dla formatu `Pipe`.
+const DEFAULT_PIPE_COMMAND: [&str; 17] = [
+    "ffmpeg",
+    "-y",
+    "-loglevel",
+    "error",
+    "-f",
+    "rawvideo",
+    "-pix_fmt",
+    "rgb24",
+    "-s",
+    "{width}x{height}",
+    "-r",
+    "{frame_rate}",
+    "-i",
+    "-",
+    "-pix_fmt",
+    "yuv420p",
+    "{output}",
+];
+
+/// Maksymalna liczba ostatnich linii wyjścia diagnostycznego zewnętrznego kodera
+/// dołączanych do komunikatu błędu.
+const PIPE_ERROR_LINES: usize = 10;
+
+/// Koder zapisujący kolejne klatki animacji (bufory RGB o rozmiarze rozdzielczości
+/// animacji) do pliku wynikowego.
+pub trait FrameEncoder {
+    /// Zapis kolejnej klatki animacji.
+    fn encode_frame(&mut self, frame: &[u8]) -> Result<(), ForestFireError>;
+
+    /// Zakończenie zapisu animacji.
+    fn finish(self: Box<Self>) -> Result<(), ForestFireError>;
+}
+
+/// Błąd zapisu pliku wynikowego.
+#[inline]
+fn output_error(config: &Configuration, error: impl Display) -> ForestFireError {
+    ForestFireError::encoding(Operation::WriteOutput, &config.output_path, error)
+}
+
+/// Utworzenie pliku wynikowego.
+#[inline]
+fn create_file(config: &Configuration) -> Result<BufWriter<File>, ForestFireError> {
+    File::create(Path::new(&config.output_path))
+        .map(BufWriter::new)
+        .map_err(|error| ForestFireError::io(Operation::CreateOutput, &config.output_path, error))
+}
+
+/// Utworzenie pliku (lub katalogu) wynikowego wraz z koderem odpowiadającym formatowi
+/// wynikowemu.
+#[inline]
+pub fn create_encoder(config: &Configuration) -> Result<Box<dyn FrameEncoder>, ForestFireError> {
+    match config.resolved_output_format() {
+        Some(OutputFormat::Gif) | None => Ok(Box::new(GifFrameEncoder::new(
+            config,
+            create_file(config)?,
+        )?)),
+        Some(OutputFormat::Apng) => Ok(Box::new(ApngFrameEncoder::new(
+            config,
+            create_file(config)?,
+        )?)),
+        Some(OutputFormat::WebP) => Ok(Box::new(WebPFrameEncoder::new(
+            config,
+            create_file(config)?,
+        )?)),
+        Some(OutputFormat::PngSequence) => Ok(Box::new(PngSequenceEncoder::new(config)?)),
+        Some(OutputFormat::Y4m) => Ok(Box::new(Y4mFrameEncoder::new(
+            config,
+            create_file(config)?,
+        )?)),
+        Some(OutputFormat::Pipe) => Ok(Box::new(PipeFrameEncoder::new(config)?)),
+    }
+}
+
+/// Koder animacji GIF z paletą globalną wyznaczoną na podstawie kolorów komórek. Zapisywany
+/// jest jedynie prostokąt obejmujący zmienione piksele, a niezmienione piksele wewnątrz niego
+/// są przezroczyste.
+struct GifFrameEncoder {
+    encoder: gif::Encoder<BufWriter<File>>,
+
+    /// Kolory palety globalnej.
+    palette: Vec<(u8, u8, u8)>,
+
+    /// Pamięć podręczna indeksów palety dla kolorów o 6 bitach na kanał (`u16::MAX` oznacza
+    /// brak wyznaczonego indeksu).
+    lookup: Vec<u16>,
+
+    /// Indeksy palety pikseli poprzedniej i bieżącej klatki.
+    previous: Vec<u8>,
+    current: Vec<u8>,
+    frame_number: u32,
+    config: Configuration,
+}
+
+/// Indeks przezroczystego koloru palety globalnej GIF.
+const GIF_TRANSPARENT_INDEX: u8 = 255;
+
+impl GifFrameEncoder {
+    #[inline]
+    fn new(config: &Configuration, writer: BufWriter<File>) -> Result<Self, ForestFireError> {
+        // Liczba odcieni na kolor zależna od jakości (z zachowaniem miejsca na kolor
+        // przezroczysty i kolory stałe)
+        let fixed_colors = 2 + config.assets.len();
+        let ramps = config.forest_color_palette.len() + 2;
+        let max_levels = (GIF_TRANSPARENT_INDEX as usize).saturating_sub(fixed_colors) / ramps;
+        let levels = (max_levels * config.gif_quality as usize / 100).max(2) as u32;
+
+        let palette = visualization::palette(config, levels, GIF_TRANSPARENT_INDEX as usize);
+
+        let mut global_palette = vec![0; 256 * 3];
+        for (entry, color) in global_palette.chunks_mut(3).zip(palette.iter()) {
+            entry.copy_from_slice(&[color.0, color.1, color.2]);
+        }
+
+        let mut encoder = gif::Encoder::new(
+            writer,
+            config.resolution.0 as u16,
+            config.resolution.1 as u16,
+            &global_palette,
+        )
+        .map_err(|error| output_error(config, error))?;
+
+        // Zapętlenie gifa
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(|error| output_error(config, error))?;
+
+        Ok(Self {
+            encoder,
+            palette,
+            lookup: vec![u16::MAX; 1 << 18],
+            previous: Vec::new(),
+            current: vec![0; (config.resolution.0 * config.resolution.1) as usize],
+            frame_number: 0,
+            config: config.clone(),
+        })
+    }
+
+    /// Indeks najbliższego koloru palety.
+    #[inline]
+    fn palette_index(&mut self, pixel: &[u8]) -> u8 {
+        let key = ((pixel[0] as usize >> 2) << 12)
+            | ((pixel[1] as usize >> 2) << 6)
+            | (pixel[2] as usize >> 2);
+
+        if self.lookup[key] == u16::MAX {
+            let distance = |color: &(u8, u8, u8)| {
+                (color.0 as i32 - pixel[0] as i32).pow(2)
+                    + (color.1 as i32 - pixel[1] as i32).pow(2)
+                    + (color.2 as i32 - pixel[2] as i32).pow(2)
+            };
+
+            self.lookup[key] = self
+                .palette
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, color)| distance(color))
+                .map_or(0, |(index, _)| index as u16);
+        }
+
+        self.lookup[key] as u8
+    }
+}
+
+impl FrameEncoder for GifFrameEncoder {
+    #[inline]
+    fn encode_frame(&mut self, frame: &[u8]) -> Result<(), ForestFireError> {
+        let width = self.config.resolution.0 as usize;
+        let height = self.config.resolution.1 as usize;
+
+        let mut current = std::mem::take(&mut self.current);
+        for (index, pixel) in current.iter_mut().zip(frame.chunks_exact(3)) {
+            *index = self.palette_index(pixel);
+        }
+        self.current = current;
+
+        // Czas trwania klatki w setnych częściach sekundy (bez kumulacji błędu zaokrągleń)
+        let frame_rate = self.config.frame_rate;
+        let frame_time = |frame_number: u32| (frame_number * 100 + frame_rate / 2) / frame_rate;
+        let delay = frame_time(self.frame_number + 1) - frame_time(self.frame_number);
+        self.frame_number += 1;
+
+        let mut gif_frame = gif::Frame {
+            delay: delay as u16,
+            dispose: gif::DisposalMethod::Keep,
+            ..gif::Frame::default()
+        };
+
+        if self.previous.is_empty() {
+            // Pierwsza klatka zapisywana jest w całości
+            gif_frame.width = width as u16;
+            gif_frame.height = height as u16;
+            gif_frame.buffer = self.current.as_slice().into();
+        } else {
+            // Prostokąt obejmujący zmienione piksele
+            let (mut left, mut top, mut right, mut bottom) = (width, height, 0, 0);
+
+            for (y, (previous_row, current_row)) in self
+                .previous
+                .chunks_exact(width)
+                .zip(self.current.chunks_exact(width))
+                .enumerate()
+            {
+                let first = previous_row
+                    .iter()
+                    .zip(current_row)
+                    .position(|(a, b)| a != b);
+
+                if let Some(first) = first {
+                    let last = previous_row
+                        .iter()
+                        .zip(current_row)
+                        .rposition(|(a, b)| a != b)
+                        .unwrap_or(first);
+
+                    left = left.min(first);
+                    right = right.max(last + 1);
+                    top = top.min(y);
+                    bottom = y + 1;
+                }
+            }
+
+            // Brak zmian: pojedynczy przezroczysty piksel
+            if right <= left {
+                (left, top, right, bottom) = (0, 0, 1, 1);
+            }
+
+            let mut buffer = Vec::with_capacity((right - left) * (bottom - top));
+
+            for y in top..bottom {
+                let row = y * width;
+
+                buffer.extend(
+                    self.previous[row + left..row + right]
+                        .iter()
+                        .zip(&self.current[row + left..row + right])
+                        .map(|(&previous, &current)| {
+                            if previous == current {
+                                GIF_TRANSPARENT_INDEX
+                            } else {
+                                current
+                            }
+                        }),
+                );
+            }
+
+            gif_frame.left = left as u16;
+            gif_frame.top = top as u16;
+            gif_frame.width = (right - left) as u16;
+            gif_frame.height = (bottom - top) as u16;
+            gif_frame.transparent = Some(GIF_TRANSPARENT_INDEX);
+            gif_frame.buffer = buffer.into();
+        }
+
+        self.encoder
+            .write_frame(&gif_frame)
+            .map_err(|error| output_error(&self.config, error))?;
+
+        if self.previous.is_empty() {
+            self.previous = self.current.clone();
+        } else {
+            std::mem::swap(&mut self.previous, &mut self.current);
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn finish(self: Box<Self>) -> Result<(), ForestFireError> {
+        let config = self.config;
+
+        self.encoder
+            .into_inner()
+            .and_then(|mut writer| writer.flush())
+            .map_err(|error| output_error(&config, error))
+    }
+}
+
+/// Koder animacji APNG.
+struct ApngFrameEncoder {
+    writer: png::Writer<BufWriter<File>>,
+    config: Configuration,
+}
+
+impl ApngFrameEncoder {
+    #[inline]
+    fn new(config: &Configuration, writer: BufWriter<File>) -> Result<Self, ForestFireError> {
+        let mut encoder = png::Encoder::new(writer, config.resolution.0, config.resolution.1);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        // Animacja zapętlona (liczba odtworzeń 0), czas trwania klatki 1/frame_rate s
+        let writer = encoder
+            .set_animated(config.frames, 0)
+            .and_then(|_| encoder.set_frame_delay(1, config.frame_rate as u16))
+            .and_then(|_| encoder.write_header())
+            .map_err(|error| output_error(config, error))?;
+
+        Ok(Self {
+            writer,
+            config: config.clone(),
+        })
+    }
+}
+
+impl FrameEncoder for ApngFrameEncoder {
+    #[inline]
+    fn encode_frame(&mut self, frame: &[u8]) -> Result<(), ForestFireError> {
+        self.writer
+            .write_image_data(frame)
+            .map_err(|error| output_error(&self.config, error))
+    }
+
+    #[inline]
+    fn finish(self: Box<Self>) -> Result<(), ForestFireError> {
+        let config = self.config;
+
+        self.writer
+            .finish()
+            .map_err(|error| output_error(&config, error))
+    }
+}
+
+/// Koder animacji WebP. Klatki są kodowane bezstratnie (VP8L) i umieszczane w kontenerze
+/// animacji (fragmenty `VP8X`, `ANIM` i `ANMF`).
+struct WebPFrameEncoder {
+    writer: BufWriter<File>,
+    frame_number: u32,
+    config: Configuration,
+}
+
+/// Zapis liczby w postaci 24-bitowej (little-endian).
+#[inline]
+fn write_u24(writer: &mut impl Write, value: u32) -> std::io::Result<()> {
+    writer.write_all(&value.to_le_bytes()[..3])
+}
+
+impl WebPFrameEncoder {
+    #[inline]
+    fn new(config: &Configuration, mut writer: BufWriter<File>) -> Result<Self, ForestFireError> {
+        let mut write_header = || -> std::io::Result<()> {
+            // Nagłówek RIFF (rozmiar uzupełniany po zapisie wszystkich klatek)
+            writer.write_all(b"RIFF")?;
+            writer.write_all(&0u32.to_le_bytes())?;
+            writer.write_all(b"WEBP")?;
+
+            // Rozszerzony format pliku z flagą animacji
+            writer.write_all(b"VP8X")?;
+            writer.write_all(&10u32.to_le_bytes())?;
+            writer.write_all(&[0x02, 0, 0, 0])?;
+            write_u24(&mut writer, config.resolution.0 - 1)?;
+            write_u24(&mut writer, config.resolution.1 - 1)?;
+
+            // Parametry animacji: kolor tła i zapętlenie (liczba odtworzeń 0)
+            writer.write_all(b"ANIM")?;
+            writer.write_all(&6u32.to_le_bytes())?;
+            writer.write_all(&[0, 0, 0, 255])?;
+            writer.write_all(&0u16.to_le_bytes())
+        };
+
+        write_header().map_err(|error| output_error(config, error))?;
+
+        Ok(Self {
+            writer,
+            frame_number: 0,
+            config: config.clone(),
+        })
+    }
+}
+
+impl FrameEncoder for WebPFrameEncoder {
+    #[inline]
+    fn encode_frame(&mut self, frame: &[u8]) -> Result<(), ForestFireError> {
+        let (width, height) = self.config.resolution;
+
+        // Bezstratne zakodowanie klatki jako pojedynczego obrazu WebP
+        let mut image = Vec::new();
+        WebPEncoder::new_lossless(&mut image)
+            .encode(frame, width, height, ColorType::Rgb8)
+            .map_err(|error| output_error(&self.config, error))?;
+
+        // Fragment `VP8L` obrazu (następujący po 12-bajtowym nagłówku RIFF)
+        let bitstream = match image.get(12..) {
+            Some(bitstream) if bitstream.starts_with(b"VP8L") => bitstream,
+            _ => {
+                return Err(output_error(
+                    &self.config,
+                    text(
+                        "Unexpected structure of the encoded WebP frame",
+                        "Nieoczekiwana struktura zakodowanej klatki WebP",
+                    ),
+                ))
+            }
+        };
+
+        // Czas trwania klatki w milisekundach (bez kumulacji błędu zaokrągleń)
+        let frame_rate = self.config.frame_rate;
+        let duration =
+            (self.frame_number + 1) * 1000 / frame_rate - self.frame_number * 1000 / frame_rate;
+        self.frame_number += 1;
+
+        let mut write_frame = || -> std::io::Result<()> {
+            self.writer.write_all(b"ANMF")?;
+            self.writer
+                .write_all(&(16 + bitstream.len() as u32).to_le_bytes())?;
+            write_u24(&mut self.writer, 0)?;
+            write_u24(&mut self.writer, 0)?;
+            write_u24(&mut self.writer, width - 1)?;
+            write_u24(&mut self.writer, height - 1)?;
+            write_u24(&mut self.writer, duration)?;
+            // Klatka nieprzezroczysta, bez mieszania z poprzednią
+            self.writer.write_all(&[0x02])?;
+            self.writer.write_all(bitstream)?;
+
+            // Fragmenty RIFF o nieparzystym rozmiarze są uzupełniane bajtem zerowym
+            if !bitstream.len().is_multiple_of(2) {
+                self.writer.write_all(&[0])?;
+            }
+
+            Ok(())
+        };
+
+        write_frame().map_err(|error| output_error(&self.config, error))
+    }
+
+    #[inline]
+    fn finish(mut self: Box<Self>) -> Result<(), ForestFireError> {
+        let mut write_size = || -> std::io::Result<()> {
+            let size = self.writer.stream_position()? - 8;
+            self.writer.seek(SeekFrom::Start(4))?;
+            self.writer.write_all(&(size as u32).to_le_bytes())?;
+            self.writer.flush()
+        };
+
+        write_size().map_err(|error| output_error(&self.config, error))
+    }
+}
+
+/// Sekwencja ponumerowanych klatek PNG zapisywanych w katalogu wynikowym.
+struct PngSequenceEncoder {
+    frame_number: u32,
+
+    /// Liczba cyfr numeru klatki w nazwie pliku.
+    digits: usize,
+    config: Configuration,
+}
+
+impl PngSequenceEncoder {
+    #[inline]
+    fn new(config: &Configuration) -> Result<Self, ForestFireError> {
+        std::fs::create_dir_all(Path::new(&config.output_path)).map_err(|error| {
+            ForestFireError::io(Operation::CreateOutputDirectory, &config.output_path, error)
+        })?;
+
+        Ok(Self {
+            frame_number: 0,
+            digits: config.frames.to_string().len().max(5),
+            config: config.clone(),
+        })
+    }
+}
+
+impl FrameEncoder for PngSequenceEncoder {
+    #[inline]
+    fn encode_frame(&mut self, frame: &[u8]) -> Result<(), ForestFireError> {
+        self.frame_number += 1;
+
+        let path = Path::new(&self.config.output_path).join(format!(
+            "frame_{:0width$}.png",
+            self.frame_number,
+            width = self.digits
+        ));
+
+        image::save_buffer(
+            &path,
+            frame,
+            self.config.resolution.0,
+            self.config.resolution.1,
+            ColorType::Rgb8,
+        )
+        .map_err(|error| output_error(&self.config, format!("{} ({})", error, path.display())))
+    }
+
+    #[inline]
+    fn finish(self: Box<Self>) -> Result<(), ForestFireError> {
+        Ok(())
+    }
+}
+
+/// Nieskompresowany strumień wideo YUV4MPEG2 (próbkowanie 4:4:4, przestrzeń barw BT.601).
+struct Y4mFrameEncoder {
+    writer: BufWriter<File>,
+
+    /// Bufor na płaszczyzny Y, U i V klatki.
+    planes: Vec<u8>,
+    config: Configuration,
+}
+
+impl Y4mFrameEncoder {
+    #[inline]
+    fn new(config: &Configuration, mut writer: BufWriter<File>) -> Result<Self, ForestFireError> {
+        writeln!(
+            writer,
+            "YUV4MPEG2 W{} H{} F{}:1 Ip A1:1 C444",
+            config.resolution.0, config.resolution.1, config.frame_rate
+        )
+        .map_err(|error| output_error(config, error))?;
+
+        Ok(Self {
+            writer,
+            planes: vec![0; (config.resolution.0 * config.resolution.1 * 3) as usize],
+            config: config.clone(),
+        })
+    }
+}
+
+impl FrameEncoder for Y4mFrameEncoder {
+    #[inline]
+    fn encode_frame(&mut self, frame: &[u8]) -> Result<(), ForestFireError> {
+        let pixels = (self.config.resolution.0 * self.config.resolution.1) as usize;
+        let (y_plane, chroma_planes) = self.planes.split_at_mut(pixels);
+        let (u_plane, v_plane) = chroma_planes.split_at_mut(pixels);
+
+        // Konwersja RGB -> YUV (zakres ograniczony)
+        for (index, pixel) in frame.chunks_exact(3).enumerate() {
+            let (r, g, b) = (pixel[0] as i32, pixel[1] as i32, pixel[2] as i32);
+
+            y_plane[index] = (((66 * r + 129 * g + 25 * b + 128) >> 8) + 16) as u8;
+            u_plane[index] = (((-38 * r - 74 * g + 112 * b + 128) >> 8) + 128) as u8;
+            v_plane[index] = (((112 * r - 94 * g - 18 * b + 128) >> 8) + 128) as u8;
+        }
+
+        self.writer
+            .write_all(b"FRAME\n")
+            .and_then(|_| self.writer.write_all(&self.planes))
+            .map_err(|error| output_error(&self.config, error))
+    }
+
+    #[inline]
+    fn finish(mut self: Box<Self>) -> Result<(), ForestFireError> {
+        self.writer
+            .flush()
+            .map_err(|error| output_error(&self.config, error))
+    }
+}
+
+/// Zewnętrzny koder (np. ffmpeg), któremu kolejne klatki RGB są przekazywane na
+/// standardowe wejście.
+struct PipeFrameEncoder {
+    child: Child,
+    stdin: Option<BufWriter<ChildStdin>>,
+
+    /// Wątek zbierający wyjście diagnostyczne kodera (aby nie blokować procesu).
+    stderr: Option<JoinHandle<String>>,
+
+    /// Polecenie kodera (do komunikatów błędów).
+    command: String,
+}
+
+impl PipeFrameEncoder {
+    #[inline]
+    fn new(config: &Configuration) -> Result<Self, ForestFireError> {
+        let arguments: Vec<String> = if config.output_command.is_empty() {
+            DEFAULT_PIPE_COMMAND
+                .iter()
+                .map(|argument| argument.to_string())
+                .collect()
+        } else {
+            config.output_command.clone()
+        };
+
+        // Podstawienie parametrów animacji w argumentach polecenia
+        let arguments: Vec<String> = arguments
+            .iter()
+            .map(|argument| {
+                argument
+                    .replace("{width}", &config.resolution.0.to_string())
+                    .replace("{height}", &config.resolution.1.to_string())
+                    .replace("{frame_rate}", &config.frame_rate.to_string())
+                    .replace("{output}", &config.output_path)
+            })
+            .collect();
+        let command = arguments.join(" ");
+
+        let mut child = Command::new(&arguments[0])
+            .args(&arguments[1..])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|error| ForestFireError::io(Operation::StartEncoder, &command, error))?;
+
+        let stdin = child.stdin.take().map(BufWriter::new);
+        let stderr = child.stderr.take().map(|mut stderr| {
+            std::thread::spawn(move || {
+                let mut output = String::new();
+                let _ = stderr.read_to_string(&mut output);
+                output
+            })
+        });
+
+        Ok(Self {
+            child,
+            stdin,
+            stderr,
+            command,
+        })
+    }
+
+    /// Zamknięcie standardowego wejścia i oczekiwanie na zakończenie kodera. W przypadku
+    /// błędu zwracany jest komunikat zawierający ostatnie linie wyjścia diagnostycznego.
+    #[inline]
+    fn wait(&mut self, error: Option<std::io::Error>) -> Result<(), ForestFireError> {
+        let flushed = match self.stdin.take() {
+            Some(mut stdin) => stdin.flush(),
+            None => Ok(()),
+        };
+        let status = self.child.wait();
+        let output = self
+            .stderr
+            .take()
+            .and_then(|stderr| stderr.join().ok())
+            .unwrap_or_default();
+
+        let details = match (status, error.or(flushed.err())) {
+            (Ok(status), _) if !status.success() => {
+                tr!(
+                    "The external encoder exited with an error ({})",
+                    "Zewnętrzny koder zakończył działanie z błędem ({})",
+                    status
+                )
+            }
+            (Err(error), _) | (Ok(_), Some(error)) => error.to_string(),
+            (Ok(_), None) => return Ok(()),
+        };
+
+        let lines: Vec<&str> = output.lines().collect();
+        let last_lines = lines[lines.len().saturating_sub(PIPE_ERROR_LINES)..].join("\n");
+
+        let message = if last_lines.is_empty() {
+            details
+        } else {
+            format!("{}\n\n{}", details, last_lines)
+        };
+
+        Err(ForestFireError::encoding(
+            Operation::RunEncoder,
+            &self.command,
+            message,
+        ))
+    }
+}
+
+impl FrameEncoder for PipeFrameEncoder {
+    #[inline]
+    fn encode_frame(&mut self, frame: &[u8]) -> Result<(), ForestFireError> {
+        let result = match self.stdin.as_mut() {
+            Some(stdin) => stdin.write_all(frame),
+            None => Ok(()),
+        };
+
+        // Koder przerwał działanie (np. zamknął wejście), zwracany jest jego błąd
+        if let Err(error) = result {
+            return self.wait(Some(error));
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn finish(mut self: Box<Self>) -> Result<(), ForestFireError> {
+        self.wait(None)
+    }
+}
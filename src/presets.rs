@@ -0,0 +1,251 @@
+//! Dziedziczenie konfiguracji i wbudowane zestawy parametrów (presety).
+//!
+//! Konfiguracja może wskazać parametr `extends` z nazwą wbudowanego presetu (np.
+//! `"green_tea"`) lub ścieżką do innego pliku konfiguracyjnego (względną wobec katalogu
+//! pliku, który ją zawiera). Parametry są łączone warstwami: najpierw konfiguracja
+//! bazowa (wraz z jej własnymi przodkami), następnie parametry podane jawnie w pliku
+//! dziedziczącym, a na końcu wartości domyślne parametrów pominiętych we wszystkich
+//! warstwach. Parametr podany w pliku dziedziczącym zastępuje wartość bazową w całości
+//! (listy, np. `assets`, nie są łączone). Nazwy presetów mają pierwszeństwo przed
+//! ścieżkami, pliki należy więc wskazywać wraz z rozszerzeniem. Dziedziczenie z plików
+//! wymaga cechy `fs`.
+
+use crate::{
+    configuration::{Configuration, ConfigurationFormat, ConfigurationLayer},
+    error::{ForestFireError, Operation},
+    tr,
+};
+
+use ron::{extensions::Extensions, Options};
+use serde_json::{Map, Value};
+
+use std::path::{Path, PathBuf};
+
+/// Wbudowane zestawy parametrów: (nazwa, konfiguracja w formacie RON).
+pub const PRESETS: [(&str, &str); 4] = [
+    ("green_tea", include_str!("../config/green_tea.ron")),
+    ("aquamarine", include_str!("../config/aquamarine.ron")),
+    ("critical", include_str!("../config/critical.ron")),
+    ("sparse", include_str!("../config/sparse.ron")),
+];
+
+/// Konfiguracja wbudowanego presetu w formacie RON.
+#[inline]
+pub fn preset(name: &str) -> Option<&'static str> {
+    PRESETS
+        .iter()
+        .find(|(preset, _)| *preset == name)
+        .map(|(_, text)| *text)
+}
+
+/// Źródło warstwy konfiguracji.
+#[derive(Clone, Copy)]
+enum Source<'a> {
+    /// Konfiguracja podana jako tekst.
+    Text,
+
+    /// Plik konfiguracyjny.
+    File(&'a str),
+
+    /// Wbudowany preset.
+    Preset(&'a str),
+}
+
+impl Source<'_> {
+    /// Nazwa źródła w opisie cyklu dziedziczenia.
+    #[inline]
+    fn name(self) -> String {
+        match self {
+            Self::Text => "<text>".to_string(),
+            Self::File(path) => path.to_string(),
+            Self::Preset(name) => name.to_string(),
+        }
+    }
+
+    /// Identyfikator źródła (do wykrywania cykli). Pliki są identyfikowane ścieżką
+    /// kanoniczną, dzięki czemu różne zapisy ścieżki do tego samego pliku są rozpoznawane.
+    #[inline]
+    fn identity(self) -> String {
+        match self {
+            Self::Text => "<text>".to_string(),
+            Self::File(path) => std::fs::canonicalize(path).map_or_else(
+                |_| path.to_string(),
+                |path| path.to_string_lossy().into_owned(),
+            ),
+            Self::Preset(name) => format!("preset:{}", name),
+        }
+    }
+}
+
+/// Złożenie konfiguracji z warstw: tekstu w podanym formacie (odczytanego z pliku `path`,
+/// jeżeli jest znany) i wszystkich konfiguracji, z których dziedziczy.
+#[inline]
+pub(crate) fn resolve_configuration(
+    text: &str,
+    format: ConfigurationFormat,
+    path: Option<&str>,
+) -> Result<Configuration, ForestFireError> {
+    let (source, operation) = match path {
+        Some(path) => (Source::File(path), Operation::LoadConfiguration),
+        None => (Source::Text, Operation::ParseConfiguration),
+    };
+
+    let fields = resolve_fields(
+        text,
+        format,
+        source,
+        operation,
+        &mut vec![(source.identity(), source.name())],
+    )?;
+
+    serde_json::from_value(Value::Object(fields))
+        .map_err(|error| ForestFireError::json(operation, path, error))
+}
+
+/// Parametry warstwy połączone z parametrami jej przodków. `chain` zawiera identyfikatory
+/// i nazwy źródeł kolejnych warstw (od pierwszej do bieżącej).
+fn resolve_fields(
+    text: &str,
+    format: ConfigurationFormat,
+    source: Source,
+    operation: Operation,
+    chain: &mut Vec<(String, String)>,
+) -> Result<Map<String, Value>, ForestFireError> {
+    let error_path = match source {
+        Source::Text => None,
+        Source::File(name) | Source::Preset(name) => Some(name),
+    };
+
+    let mut layer = parse_layer(text, format, operation, error_path)?;
+
+    let parent = match layer.extends.take() {
+        Some(parent) => parent,
+        None => return Ok(layer.into_fields()),
+    };
+
+    // Ścieżki względne są ustalane względem katalogu pliku dziedziczącego
+    let parent_path = match source {
+        Source::File(path) => Path::new(path)
+            .parent()
+            .map(|directory| directory.join(&parent)),
+        _ => None,
+    }
+    .unwrap_or_else(|| PathBuf::from(&parent))
+    .to_string_lossy()
+    .into_owned();
+
+    let preset_text = preset(&parent);
+    let parent_source = match preset_text {
+        Some(_) => Source::Preset(&parent),
+        None => Source::File(&parent_path),
+    };
+
+    let identity = parent_source.identity();
+
+    if chain.iter().any(|(known, _)| *known == identity) {
+        let cycle: Vec<&str> = chain.iter().map(|(_, name)| name.as_str()).collect();
+
+        return Err(ForestFireError::parse(
+            operation,
+            error_path,
+            tr!(
+                "cyclic inheritance of configurations: {} -> {}",
+                "cykliczne dziedziczenie konfiguracji: {} -> {}",
+                cycle.join(" -> "),
+                parent_source.name()
+            ),
+        ));
+    }
+
+    let (parent_text, parent_format) = match preset_text {
+        Some(text) => (text.to_string(), ConfigurationFormat::Ron),
+        None => {
+            #[cfg(feature = "fs")]
+            let text = std::fs::read_to_string(&parent_path);
+            // Bez cechy `fs` konfiguracja może dziedziczyć wyłącznie z presetów
+            #[cfg(not(feature = "fs"))]
+            let text: std::io::Result<String> = Err(std::io::ErrorKind::Unsupported.into());
+
+            let text = text.map_err(|error| {
+                if Path::new(&parent).extension().is_none() {
+                    // Nazwa bez rozszerzenia to najpewniej błędnie podana nazwa presetu
+                    ForestFireError::parse(
+                        operation,
+                        error_path,
+                        tr!(
+                            "unknown preset or file \"{}\" (presets: {})",
+                            "nieznany preset lub plik \"{}\" (presety: {})",
+                            parent,
+                            preset_names()
+                        ),
+                    )
+                } else {
+                    ForestFireError::io(Operation::LoadConfiguration, &parent_path, error)
+                }
+            })?;
+
+            (text, ConfigurationFormat::from_path(&parent_path))
+        }
+    };
+
+    chain.push((identity, parent_source.name()));
+
+    let mut fields = resolve_fields(
+        &parent_text,
+        parent_format,
+        parent_source,
+        Operation::LoadConfiguration,
+        chain,
+    )?;
+
+    chain.pop();
+
+    // Parametry podane jawnie zastępują wartości odziedziczone
+    fields.extend(layer.into_fields());
+
+    Ok(fields)
+}
+
+/// Parsowanie pojedynczej warstwy konfiguracji w podanym formacie.
+#[inline]
+fn parse_layer(
+    text: &str,
+    format: ConfigurationFormat,
+    operation: Operation,
+    path: Option<&str>,
+) -> Result<ConfigurationLayer, ForestFireError> {
+    match format {
+        ConfigurationFormat::Ron => Options::default()
+            .with_default_extension(Extensions::IMPLICIT_SOME)
+            .from_str(text)
+            .map_err(|error| ForestFireError::ron(operation, path, error)),
+        ConfigurationFormat::Json => serde_json::from_str(text)
+            .map_err(|error| ForestFireError::json(operation, path, error)),
+        #[cfg(feature = "cli")]
+        ConfigurationFormat::Toml => toml::from_str(text)
+            .map_err(|error| ForestFireError::toml(operation, path, error, text)),
+        #[cfg(feature = "cli")]
+        ConfigurationFormat::Yaml => serde_yaml::from_str(text)
+            .map_err(|error| ForestFireError::yaml(operation, path, error)),
+        #[cfg(not(feature = "cli"))]
+        ConfigurationFormat::Toml | ConfigurationFormat::Yaml => Err(ForestFireError::parse(
+            operation,
+            path,
+            tr!(
+                "the {:?} format requires the \"cli\" feature",
+                "format {:?} wymaga cechy \"cli\"",
+                format
+            ),
+        )),
+    }
+}
+
+/// Nazwy wbudowanych presetów oddzielone przecinkami.
+#[inline]
+fn preset_names() -> String {
+    PRESETS
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,277 @@
+//! Moduł rozszerzenia Pythona (pyo3): konfiguracja, symulacja wykonywana krok po kroku oraz
+//! tablice NumPy z migawką stanu planszy i klatki obrazu.
+//!
+//! Testy modułu: `maturin develop` i `pytest tests/python`.
+
+use crate::{
+    configuration::{
+        load_configuration, override_configuration, parse_configuration, validate_configuration,
+        Configuration,
+    },
+    simulation::{Cell, Simulation},
+    statistics::SUMMARY_COLUMNS,
+};
+
+use numpy::{
+    ndarray::{Array2, Array3, Dimension},
+    Element, PyArray,
+};
+use pyo3::{
+    exceptions::{PyKeyError, PyValueError},
+    prelude::*,
+    types::{PyDict, PyModule},
+};
+
+/// Kody stanów komórek w tablicy `Simulation.state`.
+const STATE_DEAD: u8 = 0;
+const STATE_ALIVE: u8 = 1;
+const STATE_ON_FIRE: u8 = 2;
+const STATE_SNAG: u8 = 3;
+
+/// Konfiguracja symulacji dostępna w Pythonie jako klasa `Configuration`. Parametry są
+/// odczytywane i zmieniane jak elementy słownika (`config["inflammability"] = 0.2`).
+#[pyclass(name = "Configuration")]
+#[derive(Clone)]
+pub struct PyConfiguration {
+    config: Configuration,
+}
+
+#[pymethods]
+impl PyConfiguration {
+    /// Utworzenie konfiguracji na podstawie tekstu w formacie RON.
+    #[new]
+    fn new(text: &str) -> PyResult<Self> {
+        let config =
+            parse_configuration(text).map_err(|error| PyValueError::new_err(error.to_string()))?;
+
+        Ok(Self { config })
+    }
+
+    /// Wczytanie konfiguracji z pliku (.ron lub .json, wraz z dziedziczeniem parametrów).
+    #[staticmethod]
+    fn from_file(path: &str) -> PyResult<Self> {
+        let config =
+            load_configuration(path).map_err(|error| PyValueError::new_err(error.to_string()))?;
+
+        Ok(Self { config })
+    }
+
+    /// Wartość parametru (w postaci obiektu Pythona).
+    fn __getitem__<'py>(&self, py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyAny>> {
+        let value = serde_json::to_value(&self.config)
+            .map_err(|error| PyValueError::new_err(error.to_string()))?;
+
+        let value = value
+            .get(name)
+            .ok_or_else(|| PyKeyError::new_err(name.to_string()))?;
+
+        py.import("json")?
+            .call_method1("loads", (value.to_string(),))
+    }
+
+    /// Zmiana wartości parametru (wartość jest konwertowana przez JSON).
+    fn __setitem__(&mut self, py: Python<'_>, name: &str, value: Bound<'_, PyAny>) -> PyResult<()> {
+        let value: String = py
+            .import("json")?
+            .call_method1("dumps", (value,))?
+            .extract()?;
+
+        self.config = override_configuration(&self.config, &[format!("{}={}", name, value)])
+            .map_err(|error| PyValueError::new_err(error.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Konfiguracja w postaci słownika.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let text = serde_json::to_string(&self.config)
+            .map_err(|error| PyValueError::new_err(error.to_string()))?;
+
+        py.import("json")?.call_method1("loads", (text,))
+    }
+
+    /// Sprawdzenie poprawności konfiguracji (w przypadku błędu zgłaszany jest `ValueError`).
+    fn validate(&self) -> PyResult<()> {
+        validate_configuration(&self.config)
+            .map_err(|error| PyValueError::new_err(error.to_string()))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Configuration({:?})", self.config)
+    }
+}
+
+/// Symulacja dostępna w Pythonie jako klasa `Simulation`.
+///
+/// Tablice `state`, `size`, `progress` (o wymiarach `(cells_y, cells_x)`) i `frame`
+/// (o wymiarach `(height, width, 3)`) są migawką stanu symulacji. Plansza silnika nie ma
+/// układu tablicy NumPy (komórki są typem wyliczeniowym, a plansza ma ramkę), więc po każdym
+/// wywołaniu `step`, `ignite` i `clear` jej stan jest przepisywany (jednokrotnie, niezależnie
+/// od liczby kroków) do buforów należących do obiektu. Tablice są widokami tych buforów: nie są
+/// kopiowane przy odczycie, a wcześniej pobrane widoki widzą nową migawkę.
+#[pyclass(name = "Simulation", unsendable)]
+pub struct PySimulation {
+    simulation: Simulation,
+
+    /// Stan komórek (0 - pusta, 1 - żywe drzewo, 2 - płonące drzewo, 3 - posusz).
+    state: Array2<u8>,
+
+    /// Wielkość drzew (0 dla komórek bez żywego drzewa).
+    size: Array2<f32>,
+
+    /// Postęp spalania (0 dla komórek, które nie płoną).
+    progress: Array2<f32>,
+
+    /// Klatka obrazu w formacie RGB.
+    frame: Array3<u8>,
+}
+
+impl PySimulation {
+    /// Zapisanie migawki stanu planszy i klatki obrazu w buforach udostępnianych w Pythonie.
+    #[inline]
+    fn take_snapshot(&mut self) {
+        let (cells_y, cells_x) = self.state.dim();
+        let state = self.simulation.state();
+
+        for y in 0..cells_y {
+            for x in 0..cells_x {
+                let (code, size, progress) = match state[(y + 1) * (cells_x + 2) + x + 1] {
+                    Cell::Dead => (STATE_DEAD, 0.0, 0.0),
+                    Cell::Alive { size, .. } => (STATE_ALIVE, size, 0.0),
+                    Cell::OnFire { progress } => (STATE_ON_FIRE, 0.0, progress),
+                    Cell::Snag { .. } => (STATE_SNAG, 0.0, 0.0),
+                };
+
+                self.state[(y, x)] = code;
+                self.size[(y, x)] = size;
+                self.progress[(y, x)] = progress;
+            }
+        }
+
+        self.simulation
+            .draw(self.frame.as_slice_mut().expect("ciągły bufor klatki"));
+    }
+
+    /// Widok NumPy (tylko do odczytu) na tablicę należącą do obiektu symulacji.
+    #[inline]
+    fn view<'py, T: Element, D: Dimension>(
+        slf: &Bound<'py, Self>,
+        array: impl Fn(&Self) -> &numpy::ndarray::Array<T, D>,
+    ) -> PyResult<Bound<'py, PyArray<T, D>>> {
+        let this = slf.borrow();
+
+        // Tablice nie są realokowane przez cały czas życia symulacji, a widok przechowuje
+        // referencję do obiektu symulacji, więc pamięć pozostaje ważna
+        let view = unsafe { PyArray::borrow_from_array(array(&this), slf.clone().into_any()) };
+
+        let flags = PyDict::new(slf.py());
+        flags.set_item("write", false)?;
+        view.call_method("setflags", (), Some(&flags))?;
+
+        Ok(view)
+    }
+}
+
+#[pymethods]
+impl PySimulation {
+    /// Utworzenie symulacji z losowo zainicjalizowaną planszą.
+    #[new]
+    fn new(config: &PyConfiguration) -> PyResult<Self> {
+        let config = &config.config;
+
+        validate_configuration(config).map_err(|error| PyValueError::new_err(error.to_string()))?;
+
+        let simulation =
+            Simulation::new(config).map_err(|error| PyValueError::new_err(error.to_string()))?;
+
+        let cells_x = (config.resolution.0 / config.cell_size) as usize;
+        let cells_y = (config.resolution.1 / config.cell_size) as usize;
+        let (width, height) = (config.resolution.0 as usize, config.resolution.1 as usize);
+
+        let mut py_simulation = Self {
+            simulation,
+            state: Array2::zeros((cells_y, cells_x)),
+            size: Array2::zeros((cells_y, cells_x)),
+            progress: Array2::zeros((cells_y, cells_x)),
+            frame: Array3::zeros((height, width, 3)),
+        };
+
+        py_simulation.take_snapshot();
+
+        Ok(py_simulation)
+    }
+
+    /// Wykonanie `n` kroków symulacji i zapisanie migawki stanu po ostatnim z nich.
+    #[pyo3(signature = (n = 1))]
+    fn step(&mut self, n: u32) {
+        for _ in 0..n {
+            self.simulation.step();
+        }
+
+        self.take_snapshot();
+    }
+
+    /// Podpalenie drzewa (lub posuszu) w komórce (x, y).
+    fn ignite(&mut self, x: u32, y: u32) {
+        self.simulation.ignite(x, y);
+        self.take_snapshot();
+    }
+
+    /// Usunięcie zawartości komórki (x, y).
+    fn clear(&mut self, x: u32, y: u32) {
+        self.simulation.clear(x, y);
+        self.take_snapshot();
+    }
+
+    /// Stan komórek (0 - pusta, 1 - żywe drzewo, 2 - płonące drzewo, 3 - posusz).
+    #[getter]
+    fn state<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyArray<u8, numpy::Ix2>>> {
+        Self::view(slf, |this| &this.state)
+    }
+
+    /// Wielkość drzew.
+    #[getter]
+    fn size<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyArray<f32, numpy::Ix2>>> {
+        Self::view(slf, |this| &this.size)
+    }
+
+    /// Postęp spalania.
+    #[getter]
+    fn progress<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyArray<f32, numpy::Ix2>>> {
+        Self::view(slf, |this| &this.progress)
+    }
+
+    /// Klatka obrazu w formacie RGB.
+    #[getter]
+    fn frame<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyArray<u8, numpy::Ix3>>> {
+        Self::view(slf, |this| &this.frame)
+    }
+
+    /// Konfiguracja symulacji.
+    #[getter]
+    fn config(&self) -> PyConfiguration {
+        PyConfiguration {
+            config: self.simulation.config().clone(),
+        }
+    }
+
+    /// Statystyki zebrane do tej pory.
+    fn statistics<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let statistics = PyDict::new(py);
+
+        for (name, value) in SUMMARY_COLUMNS.iter().zip(self.simulation.summary()) {
+            statistics.set_item(name, value)?;
+        }
+
+        Ok(statistics)
+    }
+}
+
+/// Moduł `forest_fire`.
+#[pymodule]
+fn forest_fire(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyConfiguration>()?;
+    module.add_class::<PySimulation>()?;
+
+    Ok(())
+}
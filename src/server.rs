@@ -0,0 +1,373 @@
+use crate::{
+    configuration::Configuration,
+    error::{ForestFireError, Operation},
+    locale::text,
+    simulation::Simulation,
+    statistics::SUMMARY_COLUMNS,
+};
+
+use console::style;
+use image::{codecs::jpeg::JpegEncoder, ColorType};
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Condvar, Mutex, MutexGuard, PoisonError,
+};
+use std::time::{Duration, Instant};
+
+/// Jakość kompresji klatek JPEG strumienia.
+const JPEG_QUALITY: u8 = 85;
+
+/// Maksymalna liczba jednocześnie obsługiwanych połączeń (kolejne są odrzucane z kodem 503).
+const MAX_CONNECTIONS: usize = 32;
+
+/// Limit czasu odczytu żądania i zapisu odpowiedzi (klient, który nie odbiera danych,
+/// jest rozłączany).
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Strona HTML z podglądem symulacji i statystykami odświeżanymi co sekundę.
+const INDEX_PAGE: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Forest-fire model</title>
+<style>
+body { background: #111; color: #ddd; font-family: monospace; margin: 2em; }
+img { max-width: 100%; image-rendering: pixelated; }
+table { border-collapse: collapse; margin-top: 1em; }
+td { padding: 0 1em 0 0; }
+</style>
+</head>
+<body>
+<img src="/stream" alt="stream">
+<table id="stats"></table>
+<script>
+async function update() {
+    try {
+        const stats = await (await fetch("/stats")).json();
+        document.getElementById("stats").innerHTML = Object.entries(stats)
+            .map(([name, value]) => `<tr><td>${name}</td><td>${value}</td></tr>`)
+            .join("");
+    } catch (error) {}
+}
+update();
+setInterval(update, 1000);
+</script>
+</body>
+</html>
+"#;
+
+/// Ostatnia klatka symulacji udostępniana klientom.
+struct SharedFrame {
+    /// Numer klatki (0 oznacza brak klatki).
+    number: u64,
+
+    /// Klatka w formacie JPEG.
+    jpeg: Arc<Vec<u8>>,
+
+    /// Statystyki w formacie JSON.
+    stats: String,
+
+    /// Koniec strumienia: symulacja zakończyła się (lub przerwała) i nie będzie kolejnych
+    /// klatek.
+    finished: bool,
+}
+
+/// Stan współdzielony przez wątek symulacji i wątki obsługujące połączenia.
+type Shared = Arc<(Mutex<SharedFrame>, Condvar)>;
+
+/// Dostęp do współdzielonej klatki. Panika innego wątku nie blokuje obsługi kolejnych
+/// połączeń (klatka jest zawsze podmieniana w całości).
+#[inline]
+fn lock(frame: &Mutex<SharedFrame>) -> MutexGuard<'_, SharedFrame> {
+    frame.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Statystyki symulacji w formacie JSON.
+#[inline]
+fn stats_json(config: &Configuration, simulation: &Simulation, frame: u32) -> String {
+    let mut stats = serde_json::Map::new();
+
+    stats.insert("frame".to_string(), frame.into());
+    stats.insert("frames".to_string(), config.frames.into());
+    stats.insert("total_steps".to_string(), config.total_steps().into());
+    stats.insert(
+        "finished".to_string(),
+        (simulation.current_statistics().steps >= config.total_steps()).into(),
+    );
+    stats.insert("seed".to_string(), config.seed.into());
+
+    for (name, value) in SUMMARY_COLUMNS.iter().zip(simulation.summary()) {
+        stats.insert(name.to_string(), value.into());
+    }
+
+    serde_json::Value::Object(stats).to_string()
+}
+
+/// Oznaczenie końca strumienia i wybudzenie oczekujących klientów przy zakończeniu wątku
+/// symulacji (również w wyniku błędu lub paniki).
+struct StreamEnd(Shared);
+
+impl Drop for StreamEnd {
+    #[inline]
+    fn drop(&mut self) {
+        let (frame, new_frame) = &*self.0;
+
+        lock(frame).finished = true;
+        new_frame.notify_all();
+    }
+}
+
+/// Wątek symulacji: kolejne klatki są kodowane do formatu JPEG z częstotliwością
+/// `frame_rate` i udostępniane klientom. Błąd kodowania jest wypisywany i kończy strumień.
+#[inline]
+fn run_simulation(config: Configuration, mut simulation: Simulation, shared: Shared) {
+    let _stream_end = StreamEnd(shared.clone());
+
+    let (width, height) = config.resolution;
+    let mut frame_buffer: Vec<u8> = vec![0; (width * height * 3) as usize];
+    let frame_duration = Duration::from_secs_f64(1.0 / config.frame_rate as f64);
+
+    for _ in 0..config.warmup_steps {
+        simulation.step();
+    }
+
+    for frame_number in 0..config.frames {
+        let frame_start = Instant::now();
+
+        for _ in 0..config.steps_per_frame {
+            simulation.step();
+        }
+
+        simulation.draw(&mut frame_buffer);
+
+        let mut jpeg = Vec::new();
+        if let Err(error) = JpegEncoder::new_with_quality(&mut jpeg, JPEG_QUALITY).encode(
+            &frame_buffer,
+            width,
+            height,
+            ColorType::Rgb8,
+        ) {
+            eprintln!(
+                "{}",
+                ForestFireError::encoding(Operation::EncodeStreamFrame, "/stream", error)
+            );
+            return;
+        }
+
+        let stats = stats_json(&config, &simulation, frame_number + 1);
+
+        {
+            let (frame, new_frame) = &*shared;
+            let mut frame = lock(frame);
+
+            frame.number += 1;
+            frame.jpeg = Arc::new(jpeg);
+            frame.stats = stats;
+            new_frame.notify_all();
+        }
+
+        if let Some(remaining) = frame_duration.checked_sub(frame_start.elapsed()) {
+            std::thread::sleep(remaining);
+        }
+    }
+
+    println!(
+        "{}",
+        style(text("Simulation finished.", "Symulacja zakończona."))
+            .green()
+            .bold()
+    );
+}
+
+/// Wysłanie odpowiedzi HTTP.
+#[inline]
+fn respond(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &[u8],
+) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-cache\r\n\
+        Connection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len()
+    )?;
+    stream.write_all(body)?;
+    stream.flush()
+}
+
+/// Strumień MJPEG: kolejne klatki wysyłane w odpowiedzi `multipart/x-mixed-replace`.
+/// Strumień kończy się po wysłaniu ostatniej klatki symulacji.
+#[inline]
+fn stream_frames(stream: &mut TcpStream, shared: &Shared) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: multipart/x-mixed-replace; boundary=frame\r\n\
+        Cache-Control: no-cache\r\nConnection: close\r\n\r\n"
+    )?;
+
+    let mut last_number = 0;
+
+    loop {
+        let jpeg = {
+            let (frame, new_frame) = &**shared;
+            let frame = new_frame
+                .wait_while(lock(frame), |frame| {
+                    frame.number == last_number && !frame.finished
+                })
+                .unwrap_or_else(PoisonError::into_inner);
+
+            if frame.number == last_number {
+                break;
+            }
+
+            last_number = frame.number;
+            frame.jpeg.clone()
+        };
+
+        write!(
+            stream,
+            "--frame\r\nContent-Type: image/jpeg\r\nContent-Length: {}\r\n\r\n",
+            jpeg.len()
+        )?;
+        stream.write_all(&jpeg)?;
+        stream.write_all(b"\r\n")?;
+        stream.flush()?;
+    }
+
+    stream.write_all(b"--frame--\r\n")?;
+    stream.flush()
+}
+
+/// Obsługa połączenia HTTP.
+#[inline]
+fn handle_connection(mut stream: TcpStream, shared: Shared) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    // Pominięcie nagłówków żądania
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+
+    if method != "GET" {
+        return respond(
+            &mut stream,
+            "405 Method Not Allowed",
+            "text/plain",
+            b"Method Not Allowed",
+        );
+    }
+
+    match path.split('?').next().unwrap_or("") {
+        "/" | "/index.html" => respond(
+            &mut stream,
+            "200 OK",
+            "text/html; charset=utf-8",
+            INDEX_PAGE.as_bytes(),
+        ),
+        "/stream" => stream_frames(&mut stream, &shared),
+        "/frame.jpg" => {
+            let jpeg = lock(&shared.0).jpeg.clone();
+            respond(&mut stream, "200 OK", "image/jpeg", &jpeg)
+        }
+        "/stats" => {
+            let stats = lock(&shared.0).stats.clone();
+            respond(&mut stream, "200 OK", "application/json", stats.as_bytes())
+        }
+        _ => respond(&mut stream, "404 Not Found", "text/plain", b"Not Found"),
+    }
+}
+
+/// Serwer HTTP udostępniający przebieg symulacji: strona z podglądem (`/`), strumień MJPEG
+/// (`/stream`), ostatnia klatka (`/frame.jpg`) i statystyki w formacie JSON (`/stats`).
+/// Po zakończeniu symulacji serwer udostępnia ostatnią klatkę do czasu przerwania programu.
+#[inline]
+pub fn serve(config: &Configuration, address: &str) -> Result<(), ForestFireError> {
+    let listener = TcpListener::bind(address)
+        .map_err(|error| ForestFireError::io(Operation::StartServer, address, error))?;
+
+    serve_listener(config, listener)
+}
+
+/// Serwer HTTP (jak `serve`) obsługujący połączenia przychodzące na podanym gnieździe.
+#[inline]
+pub fn serve_listener(
+    config: &Configuration,
+    listener: TcpListener,
+) -> Result<(), ForestFireError> {
+    let simulation = Simulation::new(config)?;
+
+    let shared: Shared = Arc::new((
+        Mutex::new(SharedFrame {
+            number: 0,
+            jpeg: Arc::new(Vec::new()),
+            stats: stats_json(config, &simulation, 0),
+            finished: false,
+        }),
+        Condvar::new(),
+    ));
+
+    if let Ok(address) = listener.local_addr() {
+        println!(
+            "\n{} {}",
+            style(text(
+                "Simulation preview server:",
+                "Serwer podglądu symulacji:"
+            ))
+            .green()
+            .bold(),
+            style(format!("http://{}/", address)).cyan().bold()
+        );
+    }
+
+    {
+        let config = config.clone();
+        let shared = shared.clone();
+        std::thread::spawn(move || run_simulation(config, simulation, shared));
+    }
+
+    let connections = Arc::new(AtomicUsize::new(0));
+
+    for mut stream in listener.incoming().flatten() {
+        if connections.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+            connections.fetch_sub(1, Ordering::SeqCst);
+
+            let _ = stream.set_write_timeout(Some(IO_TIMEOUT)).and_then(|()| {
+                respond(
+                    &mut stream,
+                    "503 Service Unavailable",
+                    "text/plain",
+                    b"Service Unavailable",
+                )
+            });
+            continue;
+        }
+
+        let shared = shared.clone();
+        let connections = connections.clone();
+
+        // Błędy połączeń (np. rozłączenie klienta) nie przerywają pracy serwera
+        std::thread::spawn(move || {
+            let _ = handle_connection(stream, shared);
+            connections.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+
+    Ok(())
+}
@@ -1,35 +1,57 @@
-use crate::{configuration::Configuration, visualization::draw};
+use crate::{
+    assets::Assets,
+    configuration::Configuration,
+    error::ForestFireError,
+    firefighting::Firefighters,
+    management::Management,
+    statistics::{Statistics, Summary},
+    visualization::draw,
+};
+
+#[cfg(feature = "cli")]
+use crate::{locale::text, output::create_encoder};
 
+#[cfg(feature = "cli")]
 use console::{style, Term};
+
 use rand::{
     distributions::{Distribution, Uniform},
-    rngs::ThreadRng,
+    rngs::StdRng,
     seq::SliceRandom,
-    thread_rng,
+    thread_rng, Rng, SeedableRng,
 };
 
-use image::{
-    codecs::gif::{GifEncoder, Repeat},
-    ColorType,
+#[cfg(feature = "cli")]
+use std::io::Write;
+#[cfg(feature = "cli")]
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex,
 };
 
-use std::fs::File;
-use std::io::BufWriter;
-use std::path::Path;
-
 /// Bazowy rozmiar drzewa.
-const BASE_TREE_SIZE: f32 = 0.2;
+pub const BASE_TREE_SIZE: f32 = 0.2;
 
 /// Reprezentacja komórki.
+#[derive(Clone)]
 pub enum Cell {
-    Alive { size: f32, color: (u8, u8, u8) },
-    OnFire { progress: f32 },
+    Alive {
+        size: f32,
+        color: (u8, u8, u8),
+        age: u32,
+    },
+    Snag {
+        integrity: f32,
+    },
+    OnFire {
+        progress: f32,
+    },
     Dead,
 }
 
 /// Losowa inicjalizacja planszy.
 #[inline]
-pub fn initialize_grid(rng: &mut ThreadRng, config: &Configuration) -> Vec<Cell> {
+pub fn initialize_grid(rng: &mut StdRng, config: &Configuration) -> Vec<Cell> {
     let cells_x = config.resolution.0 / config.cell_size;
     let cells_y = config.resolution.1 / config.cell_size;
 
@@ -39,9 +61,18 @@ pub fn initialize_grid(rng: &mut ThreadRng, config: &Configuration) -> Vec<Cell>
     (0..(cells_x + 2) * (cells_y + 2))
         .map(|_| {
             if flat_distr.sample(rng) <= config.alive_fraction {
+                let size = distr_size.sample(rng);
+
+                // Wiek drzewa odpowiadający jego rozmiarowi, ograniczony do wieku referencyjnego
+                // śmiertelności (przy zerowym tempie wzrostu wiek byłby praktycznie
+                // nieskończony, a cały początkowy las obumarłby w pierwszym kroku)
+                let age = ((size - BASE_TREE_SIZE) / config.growth_rate.max(f32::EPSILON))
+                    .min(config.mortality_age as f32);
+
                 Cell::Alive {
-                    size: distr_size.sample(rng),
-                    color: config.forest_color_palette.choose(rng).unwrap().clone(),
+                    size,
+                    color: *config.forest_color_palette.choose(rng).unwrap(),
+                    age: age as u32,
                 }
             } else {
                 Cell::Dead
@@ -61,13 +92,45 @@ pub fn initialize_grid_default(config: &Configuration) -> Vec<Cell> {
         .collect()
 }
 
+/// Liczba sąsiadów komórki (sąsiedztwo Moore'a) spełniających zadany warunek.
+#[inline]
+fn count_neighbours(
+    state: &[Cell],
+    cells_x: usize,
+    index_x: usize,
+    index_y: usize,
+    predicate: fn(&Cell) -> bool,
+) -> i32 {
+    let mut count = 0;
+
+    for neighbour_y in index_y - 1..=index_y + 1 {
+        for neighbour_x in index_x - 1..=index_x + 1 {
+            if (neighbour_x != index_x || neighbour_y != index_y)
+                && predicate(&state[neighbour_y * cells_x + neighbour_x])
+            {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+/// Prawdopodobieństwo śmierci naturalnej drzewa w danym wieku.
+#[inline]
+fn mortality_probability(config: &Configuration, age: u32) -> f32 {
+    (config.mortality_probability
+        * (age as f32 / config.mortality_age as f32).powf(config.mortality_exponent))
+    .min(1.0)
+}
+
 /// Generowania nowego stanu (kolejny krok symulacji).
 #[inline]
 pub fn generate_current_state(
-    rng: &mut ThreadRng,
+    rng: &mut StdRng,
     config: &Configuration,
-    previous_state: &Vec<Cell>,
-    current_state: &mut Vec<Cell>,
+    previous_state: &[Cell],
+    current_state: &mut [Cell],
 ) {
     let cells_x = (config.resolution.0 / config.cell_size) as usize + 2;
     let cells_y = (config.resolution.1 / config.cell_size) as usize + 2;
@@ -78,49 +141,12 @@ pub fn generate_current_state(
         for index_x in 1..cells_x - 1 {
             match previous_state[index_y * cells_x + index_x] {
                 // Rozwiązanie dla komórki żywej
-                Cell::Alive { size, color } => {
-                    let mut trees_on_fire = 0;
-
+                Cell::Alive { size, color, age } => {
                     // Sprawdzenie z iloma płonącymi drzewami sąsiaduje
-                    match previous_state[(index_y - 1) * cells_x + (index_x - 1)] {
-                        Cell::OnFire { .. } => trees_on_fire += 1,
-                        _ => {}
-                    }
-
-                    match previous_state[(index_y - 1) * cells_x + index_x] {
-                        Cell::OnFire { .. } => trees_on_fire += 1,
-                        _ => {}
-                    }
-
-                    match previous_state[(index_y - 1) * cells_x + (index_x + 1)] {
-                        Cell::OnFire { .. } => trees_on_fire += 1,
-                        _ => {}
-                    }
-
-                    match previous_state[index_y * cells_x + (index_x - 1)] {
-                        Cell::OnFire { .. } => trees_on_fire += 1,
-                        _ => {}
-                    }
-
-                    match previous_state[index_y * cells_x + (index_x + 1)] {
-                        Cell::OnFire { .. } => trees_on_fire += 1,
-                        _ => {}
-                    }
-
-                    match previous_state[(index_y + 1) * cells_x + (index_x - 1)] {
-                        Cell::OnFire { .. } => trees_on_fire += 1,
-                        _ => {}
-                    }
-
-                    match previous_state[(index_y + 1) * cells_x + index_x] {
-                        Cell::OnFire { .. } => trees_on_fire += 1,
-                        _ => {}
-                    }
-
-                    match previous_state[(index_y + 1) * cells_x + (index_x + 1)] {
-                        Cell::OnFire { .. } => trees_on_fire += 1,
-                        _ => {}
-                    }
+                    let trees_on_fire =
+                        count_neighbours(previous_state, cells_x, index_x, index_y, |cell| {
+                            matches!(cell, Cell::OnFire { .. })
+                        });
 
                     // Sprawdzenie czy drzewo stanie w ogniu, proces zależny od wielkości drzewa
                     if flat_distr.sample(rng)
@@ -130,12 +156,44 @@ pub fn generate_current_state(
                     // Sprawdzenie czy drzewo dokona samozapłonu, proces zależny od wielkości drzewa
                     } else if flat_distr.sample(rng) < config.self_ignition_probability * size {
                         current_state[index_y * cells_x + index_x] = Cell::OnFire { progress: 1.0 };
-                    // Jeżeli nie nastąpi nic z powyższych, drzewo rośnie
+                    // Sprawdzenie czy drzewo umrze śmiercią naturalną (zostaje posusz)
+                    } else if flat_distr.sample(rng) < mortality_probability(config, age) {
+                        current_state[index_y * cells_x + index_x] = Cell::Snag { integrity: 1.0 };
+                    // Jeżeli nie nastąpi nic z powyższych, drzewo rośnie i się starzeje
                     } else {
                         current_state[index_y * cells_x + index_x] = Cell::Alive {
                             size: 1.0_f32.min(size + config.growth_rate),
                             color,
+                            age: age.saturating_add(1),
+                        };
+                    }
+                }
+                // Rozwiązanie dla martwego, stojącego drzewa (posuszu)
+                Cell::Snag { integrity } => {
+                    // Sprawdzenie z iloma płonącymi drzewami sąsiaduje
+                    let trees_on_fire =
+                        count_neighbours(previous_state, cells_x, index_x, index_y, |cell| {
+                            matches!(cell, Cell::OnFire { .. })
+                        });
+
+                    let new_integrity = integrity - config.snag_decay_rate;
+
+                    // Sprawdzenie czy posusz stanie w ogniu (niezależnie od wielkości)
+                    if flat_distr.sample(rng)
+                        < 1.0 - (1.0 - config.snag_inflammability).powi(trees_on_fire)
+                    {
+                        current_state[index_y * cells_x + index_x] = Cell::OnFire { progress: 1.0 };
+                    // Sprawdzenie czy posusz dokona samozapłonu
+                    } else if flat_distr.sample(rng) < config.self_ignition_probability {
+                        current_state[index_y * cells_x + index_x] = Cell::OnFire { progress: 1.0 };
+                    // Jeżeli posusz nie rozpadł się, to stoi dalej
+                    } else if new_integrity > 0.0 {
+                        current_state[index_y * cells_x + index_x] = Cell::Snag {
+                            integrity: new_integrity,
                         };
+                    // Jeżeli posusz się rozpadł, komórka jest pusta
+                    } else {
+                        current_state[index_y * cells_x + index_x] = Cell::Dead;
                     }
                 }
                 // Rozwiązanie dla płonącego drzewa
@@ -154,48 +212,11 @@ pub fn generate_current_state(
                 }
                 // Rozwiązanie dla martwego drzewa
                 Cell::Dead => {
-                    let mut trees_alive = 0;
-
                     // Sprawdzenie z iloma żywymi drzewami sąsiaduje ta komórka
-                    match previous_state[(index_y - 1) * cells_x + (index_x - 1)] {
-                        Cell::Alive { .. } => trees_alive += 1,
-                        _ => {}
-                    }
-
-                    match previous_state[(index_y - 1) * cells_x + index_x] {
-                        Cell::Alive { .. } => trees_alive += 1,
-                        _ => {}
-                    }
-
-                    match previous_state[(index_y - 1) * cells_x + (index_x + 1)] {
-                        Cell::Alive { .. } => trees_alive += 1,
-                        _ => {}
-                    }
-
-                    match previous_state[index_y * cells_x + (index_x - 1)] {
-                        Cell::Alive { .. } => trees_alive += 1,
-                        _ => {}
-                    }
-
-                    match previous_state[index_y * cells_x + (index_x + 1)] {
-                        Cell::Alive { .. } => trees_alive += 1,
-                        _ => {}
-                    }
-
-                    match previous_state[(index_y + 1) * cells_x + (index_x - 1)] {
-                        Cell::Alive { .. } => trees_alive += 1,
-                        _ => {}
-                    }
-
-                    match previous_state[(index_y + 1) * cells_x + index_x] {
-                        Cell::Alive { .. } => trees_alive += 1,
-                        _ => {}
-                    }
-
-                    match previous_state[(index_y + 1) * cells_x + (index_x + 1)] {
-                        Cell::Alive { .. } => trees_alive += 1,
-                        _ => {}
-                    }
+                    let trees_alive =
+                        count_neighbours(previous_state, cells_x, index_x, index_y, |cell| {
+                            matches!(cell, Cell::Alive { .. })
+                        });
 
                     // Sprawdzenie czy drzewo wykiełkuje (kontakt z żyjącymi drzewami)
                     if flat_distr.sample(rng)
@@ -203,13 +224,15 @@ pub fn generate_current_state(
                     {
                         current_state[index_y * cells_x + index_x] = Cell::Alive {
                             size: BASE_TREE_SIZE,
-                            color: config.forest_color_palette.choose(rng).unwrap().clone(),
+                            color: *config.forest_color_palette.choose(rng).unwrap(),
+                            age: 0,
                         }
                     // Sprawdzenie czy drzewo wykiełkuje (losowo)
                     } else if flat_distr.sample(rng) < config.random_sprout_probability {
                         current_state[index_y * cells_x + index_x] = Cell::Alive {
                             size: BASE_TREE_SIZE,
-                            color: config.forest_color_palette.choose(rng).unwrap().clone(),
+                            color: *config.forest_color_palette.choose(rng).unwrap(),
+                            age: 0,
                         };
                     // Jeśli nic się nie stanie to drzewo wciąż jest martwe (nie ma drzewa)
                     } else {
@@ -227,79 +250,344 @@ pub fn swap_states(previous_state: &mut Vec<Cell>, current_state: &mut Vec<Cell>
     std::mem::swap(previous_state, current_state);
 }
 
+/// Stan symulacji wraz z podsystemami (straż pożarna, chronione obiekty, zarządzanie lasem).
+pub struct Simulation {
+    config: Configuration,
+    rng: StdRng,
+    previous_state: Vec<Cell>,
+    current_state: Vec<Cell>,
+    firefighters: Firefighters,
+    assets: Assets,
+    management: Management,
+    statistics: Statistics,
+}
+
+impl Simulation {
+    /// Utworzenie symulacji z losowo zainicjalizowaną planszą.
+    #[inline]
+    pub fn new(config: &Configuration) -> Result<Self, ForestFireError> {
+        let mut rng = StdRng::seed_from_u64(config.seed.unwrap_or_else(|| thread_rng().gen()));
+        let previous_state = initialize_grid(&mut rng, config);
+        let current_state = initialize_grid_default(config);
+        let firefighters = Firefighters::new(&mut rng, config);
+        let assets = Assets::new(config)?;
+        let management = Management::new(config);
+        let statistics = Statistics::new(config);
+
+        Ok(Self {
+            config: config.clone(),
+            rng,
+            previous_state,
+            current_state,
+            firefighters,
+            assets,
+            management,
+            statistics,
+        })
+    }
+
+    /// Wykonanie kroku symulacji.
+    #[inline]
+    pub fn step(&mut self) {
+        let config = &self.config;
+
+        // Generowanie nowego stanu
+        generate_current_state(
+            &mut self.rng,
+            config,
+            &self.previous_state,
+            &mut self.current_state,
+        );
+
+        // Działania jednostek straży pożarnej
+        self.firefighters
+            .update(config, &self.previous_state, &mut self.current_state);
+
+        // Aktualizacja statystyk
+        self.statistics
+            .update(config, &self.previous_state, &self.current_state);
+        self.assets
+            .update(&self.current_state, self.statistics.steps);
+
+        // Zabiegi zarządzania lasem (między krokami symulacji)
+        self.management.update(
+            &mut self.rng,
+            config,
+            &mut self.current_state,
+            &mut self.statistics,
+        );
+
+        // Zamiana plansz (mechanizm oszczędzania na dealokacji pamięci)
+        swap_states(&mut self.previous_state, &mut self.current_state);
+    }
+
+    /// Rysowanie aktualnego stanu do bufora klatki.
+    #[inline]
+    pub fn draw(&self, frame_buffer: &mut [u8]) {
+        draw(&self.config, &self.previous_state, frame_buffer);
+        self.assets
+            .draw(&self.config, &self.previous_state, frame_buffer);
+        self.firefighters.draw(&self.config, frame_buffer);
+    }
+
+    /// Konfiguracja symulacji.
+    #[inline]
+    pub fn config(&self) -> &Configuration {
+        &self.config
+    }
+
+    /// Konfiguracja symulacji (parametry mogą być zmieniane między krokami).
+    #[inline]
+    pub fn config_mut(&mut self) -> &mut Configuration {
+        &mut self.config
+    }
+
+    /// Aktualny stan planszy (z obramowaniem o szerokości jednej komórki).
+    #[inline]
+    pub fn state(&self) -> &[Cell] {
+        &self.previous_state
+    }
+
+    /// Indeks komórki (x, y) na planszy z obramowaniem lub `None`, jeżeli komórka leży
+    /// poza planszą.
+    #[inline]
+    fn cell_index(&self, x: u32, y: u32) -> Option<usize> {
+        let cells_x = self.config.resolution.0 / self.config.cell_size;
+        let cells_y = self.config.resolution.1 / self.config.cell_size;
+
+        (x < cells_x && y < cells_y).then(|| ((y + 1) * (cells_x + 2) + x + 1) as usize)
+    }
+
+    /// Podpalenie drzewa (lub posuszu) w komórce (x, y) między krokami symulacji.
+    #[inline]
+    pub fn ignite(&mut self, x: u32, y: u32) {
+        if let Some(index) = self.cell_index(x, y) {
+            if matches!(
+                self.previous_state[index],
+                Cell::Alive { .. } | Cell::Snag { .. }
+            ) {
+                self.previous_state[index] = Cell::OnFire { progress: 1.0 };
+                self.statistics.register_external_ignition(index);
+            }
+        }
+    }
+
+    /// Usunięcie zawartości komórki (x, y) między krokami symulacji.
+    #[inline]
+    pub fn clear(&mut self, x: u32, y: u32) {
+        if let Some(index) = self.cell_index(x, y) {
+            self.previous_state[index] = Cell::Dead;
+        }
+    }
+
+    /// Bieżące liczności stanów komórek i statystyki pożarów (bez kopiowania, bez statystyk
+    /// podsystemów).
+    #[inline]
+    pub fn current_statistics(&self) -> &Statistics {
+        &self.statistics
+    }
+
+    /// Statystyki zebrane do tej pory.
+    #[inline]
+    pub fn statistics(&self) -> Statistics {
+        let mut statistics = self.statistics.clone();
+
+        statistics.extinguished_cells = self.firefighters.extinguished_cells;
+        statistics.treated_cells = self.management.treated_cells;
+        statistics.assets = self.assets.reports.clone();
+
+        statistics
+    }
+
+    /// Wartości statystyk zbiorczych (jak `statistics().summary()`, bez kopiowania statystyk).
+    #[inline]
+    pub fn summary(&self) -> Summary {
+        self.statistics.summary_with(
+            self.firefighters.extinguished_cells,
+            self.management.treated_cells,
+        )
+    }
+}
+
 /// Główna procedura symulacji.
+#[cfg(feature = "cli")]
 #[inline]
-pub fn simulate(config: &Configuration) -> Result<(), String> {
-    let mut rng = thread_rng();
-    let mut previous_state = initialize_grid(&mut rng, config);
-    let mut current_state = initialize_grid_default(config);
+pub fn simulate(config: &Configuration) -> Result<Statistics, ForestFireError> {
+    let mut simulation = Simulation::new(config)?;
 
     // Bufor na klatkę obrazu (wielokrotnego użycia, mechanizm oszczędzania na
     // dealokacji pamięci)
     let mut frame_buffer: Vec<u8> =
         vec![0; (config.resolution.0 * config.resolution.1 * 3) as usize];
 
-    let gif_file = match File::create(Path::new(&config.output_path)) {
-        Ok(file) => file,
-        Err(error) => {
-            return Err(format!(
-                "{} ({})\n\nSzczegóły:\n    {}\n",
-                style("Błąd podczas tworzenia pliku wynikowego!")
-                    .red()
-                    .bold(),
-                style(format!("\"{}\"", config.output_path))
-                    .cyan()
-                    .italic()
-                    .bold(),
-                error
-            ));
-        }
-    };
-
-    // Utworzenie writera dla formatu GIF
-    let gif_writer = BufWriter::new(gif_file);
-    let mut gif_encoder = GifEncoder::new_with_speed(gif_writer, config.frame_rate as i32);
-
-    // Zapętlenie gifa
-    gif_encoder.set_repeat(Repeat::Infinite).unwrap();
+    // Utworzenie pliku wynikowego w zadanym formacie
+    let mut encoder = create_encoder(config)?;
 
     print!("\n\n");
     let term = Term::stdout();
     term.hide_cursor().unwrap();
 
+    // Rozgrzewka symulacji (bez zapisu animacji)
+    for step_number in 0..config.warmup_steps {
+        term.move_cursor_left(1000).unwrap();
+        print!(
+            "{} {}/{}",
+            style(text("Simulation warm-up:", "Rozgrzewka symulacji:"))
+                .green()
+                .bold(),
+            step_number + 1,
+            config.warmup_steps
+        );
+
+        simulation.step();
+    }
+
+    if config.warmup_steps > 0 {
+        term.clear_line().unwrap();
+    }
+
     // Główna pętla symulacji
     for frame_number in 0..config.frames {
         term.move_cursor_left(1000).unwrap();
         print!(
             "{} {}/{}",
-            style("Postęp symulacji:").green().bold(),
+            style(text("Simulation progress:", "Postęp symulacji:"))
+                .green()
+                .bold(),
             frame_number + 1,
             config.frames
         );
 
-        // Generowanie nowego stanu
-        generate_current_state(&mut rng, config, &previous_state, &mut current_state);
+        // Generowanie nowego stanu (rysowany jest co `steps_per_frame` krok)
+        for _ in 0..config.steps_per_frame {
+            simulation.step();
+        }
 
         // Rysowanie nowego stanu
-        draw(config, &current_state, &mut frame_buffer);
+        simulation.draw(&mut frame_buffer);
 
         // Zapis nowego stanu
-        gif_encoder
-            .encode(
-                &frame_buffer,
-                config.resolution.0,
-                config.resolution.1,
-                ColorType::Rgb8,
-            )
-            .unwrap();
-
-        // Zamiana plansz (mechanizm oszczędzania na dealokacji pamięci)
-        swap_states(&mut previous_state, &mut current_state);
+        if let Err(error) = encoder.encode_frame(&frame_buffer) {
+            println!();
+            term.show_cursor().unwrap();
+            return Err(error);
+        }
     }
 
     // Zakończenie symulacji
-    println!("\n{}", style("Ukończono!").green().bold());
+    if let Err(error) = encoder.finish() {
+        println!();
+        term.show_cursor().unwrap();
+        return Err(error);
+    }
+
+    println!("\n{}", style(text("Done!", "Ukończono!")).green().bold());
+    term.show_cursor().unwrap();
+
+    Ok(simulation.statistics())
+}
+
+/// Symulacja bez generowania animacji (jedynie statystyki).
+#[cfg(feature = "cli")]
+#[inline]
+pub fn simulate_without_output(
+    config: &Configuration,
+    description: &str,
+) -> Result<Statistics, ForestFireError> {
+    let mut simulation = Simulation::new(config)?;
+
+    println!();
+    let term = Term::stdout();
+    term.hide_cursor().unwrap();
+
+    let total_steps = config.total_steps();
+
+    for step_number in 0..total_steps {
+        term.move_cursor_left(1000).unwrap();
+        print!(
+            "{} {}/{}",
+            style(description).green().bold(),
+            step_number + 1,
+            total_steps
+        );
+
+        simulation.step();
+    }
+
+    println!("\n{}", style(text("Done!", "Ukończono!")).green().bold());
     term.show_cursor().unwrap();
 
-    Ok(())
+    Ok(simulation.statistics())
+}
+
+/// Symulacja referencyjna bez zarządzania lasem (bez generowania animacji).
+#[cfg(feature = "cli")]
+#[inline]
+pub fn simulate_unmanaged(config: &Configuration) -> Result<Statistics, ForestFireError> {
+    let unmanaged_config = Configuration {
+        management_interval: 0,
+        ..config.clone()
+    };
+
+    simulate_without_output(
+        &unmanaged_config,
+        text(
+            "Reference simulation progress (without management):",
+            "Postęp symulacji referencyjnej (bez zarządzania):",
+        ),
+    )
+}
+
+/// Równoległe przeprowadzenie wielu symulacji (bez generowania animacji) w `jobs` wątkach.
+/// Wyniki są przekazywane do `on_result` wraz z indeksem konfiguracji, w kolejności
+/// zakończenia przebiegów.
+#[cfg(feature = "cli")]
+#[inline]
+pub fn simulate_parallel<F>(configs: &[Configuration], jobs: usize, description: &str, on_result: F)
+where
+    F: Fn(usize, Result<Statistics, ForestFireError>) + Sync,
+{
+    let next_run = AtomicUsize::new(0);
+    let finished_runs = Mutex::new(0_usize);
+
+    println!();
+    let term = Term::stdout();
+    term.hide_cursor().unwrap();
+
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.max(1).min(configs.len()) {
+            scope.spawn(|| loop {
+                let index = next_run.fetch_add(1, Ordering::Relaxed);
+
+                let Some(config) = configs.get(index) else {
+                    break;
+                };
+
+                let result = Simulation::new(config).map(|mut simulation| {
+                    for _ in 0..config.total_steps() {
+                        simulation.step();
+                    }
+
+                    simulation.statistics()
+                });
+
+                on_result(index, result);
+
+                let mut finished_runs = finished_runs.lock().unwrap();
+                *finished_runs += 1;
+
+                term.move_cursor_left(1000).unwrap();
+                print!(
+                    "{} {}/{}",
+                    style(description).green().bold(),
+                    finished_runs,
+                    configs.len()
+                );
+                std::io::stdout().flush().unwrap();
+            });
+        }
+    });
+
+    println!("\n{}", style(text("Done!", "Ukończono!")).green().bold());
+    term.show_cursor().unwrap();
 }
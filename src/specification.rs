@@ -0,0 +1,532 @@
+//! Specyfikacja pliku konfiguracyjnego generowana na podstawie struktury `Configuration`:
+//! nazwy i typy parametrów, wartości domyślne, dopuszczalne zakresy i opisy, a także
+//! schemat JSON Schema (podpowiedzi i sprawdzanie plików konfiguracyjnych w edytorach).
+
+use crate::{
+    configuration::{
+        parse_configuration, Asset, Configuration, FirefightingStrategy, ManagementAction,
+        ManagementStrategy, OutputFormat, PARAMETERS,
+    },
+    error::Violation,
+    locale::text,
+    presets::{preset, PRESETS},
+    tr,
+};
+
+use serde_json::{json, Map, Value};
+
+/// Adres metaschematu, zgodnie z którym zapisany jest schemat konfiguracji (wersja draft-07,
+/// obsługiwana przez większość edytorów).
+const META_SCHEMA: &str = "http://json-schema.org/draft-07/schema#";
+
+/// Dopuszczalny zakres wartości parametru liczbowego.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds {
+    /// Najmniejsza dopuszczalna wartość.
+    pub minimum: f64,
+
+    /// Największa dopuszczalna wartość (brak oznacza zakres nieograniczony od góry).
+    pub maximum: Option<f64>,
+
+    /// Czy wartość `minimum` jest wykluczona z zakresu.
+    pub exclusive_minimum: bool,
+}
+
+impl Bounds {
+    /// Zakres wartości większych lub równych `minimum`.
+    #[inline]
+    pub const fn at_least(minimum: f64) -> Self {
+        Self {
+            minimum,
+            maximum: None,
+            exclusive_minimum: false,
+        }
+    }
+
+    /// Zakres wartości większych od `minimum`.
+    #[inline]
+    pub const fn greater_than(minimum: f64) -> Self {
+        Self {
+            minimum,
+            maximum: None,
+            exclusive_minimum: true,
+        }
+    }
+
+    /// Zakres wartości od `minimum` do `maximum` (włącznie).
+    #[inline]
+    pub const fn between(minimum: f64, maximum: f64) -> Self {
+        Self {
+            minimum,
+            maximum: Some(maximum),
+            exclusive_minimum: false,
+        }
+    }
+
+    /// Zapis zakresu (`1..100`, `>= 1`, `> 0.0`), dla parametrów całkowitoliczbowych bez
+    /// części ułamkowej.
+    #[inline]
+    pub fn describe(&self, integer: bool) -> String {
+        let number = |value: f64| format_bound(value, integer);
+
+        match (self.maximum, self.exclusive_minimum) {
+            (Some(maximum), _) => format!("{}..{}", number(self.minimum), number(maximum)),
+            (None, true) => format!("> {}", number(self.minimum)),
+            (None, false) => format!(">= {}", number(self.minimum)),
+        }
+    }
+
+    /// Czy wartość należy do zakresu (NaN nie należy do żadnego zakresu).
+    #[inline]
+    pub fn contains(&self, value: f64) -> bool {
+        let above_minimum = if self.exclusive_minimum {
+            value > self.minimum
+        } else {
+            value >= self.minimum
+        };
+
+        above_minimum && self.maximum.is_none_or(|maximum| value <= maximum)
+    }
+
+    /// Opis zakresu w komunikacie walidacji (`in the range 1..100`, `greater than 0`).
+    #[inline]
+    pub fn constraint(&self, integer: bool) -> String {
+        match (self.maximum, self.exclusive_minimum) {
+            (Some(_), _) => tr!(
+                "in the range {}",
+                "wartości z zakresu {}",
+                self.describe(integer)
+            ),
+            (None, true) => tr!(
+                "greater than {}",
+                "wartości większe od {}",
+                format_bound(self.minimum, integer)
+            ),
+            // Dla liczb całkowitych `>= n` oznacza `> n - 1`
+            (None, false) if integer => tr!(
+                "greater than {}",
+                "wartości większe od {}",
+                format_bound(self.minimum - 1.0, integer)
+            ),
+            (None, false) => tr!(
+                "greater than or equal to {}",
+                "wartości większe lub równe {}",
+                format_bound(self.minimum, integer)
+            ),
+        }
+    }
+
+    /// Uzupełnienie schematu liczby o granice zakresu.
+    #[inline]
+    fn restrict(&self, schema: &mut Value) {
+        let integer = schema["type"] == "integer";
+        let number = |value: f64| {
+            if integer {
+                json!(value as u64)
+            } else {
+                json!(value)
+            }
+        };
+
+        if self.exclusive_minimum {
+            schema["exclusiveMinimum"] = number(self.minimum);
+        } else {
+            schema["minimum"] = number(self.minimum);
+        }
+
+        if let Some(maximum) = self.maximum {
+            schema["maximum"] = number(maximum);
+        }
+    }
+}
+
+/// Zapis granicy zakresu: bez części ułamkowej dla parametrów całkowitoliczbowych.
+#[inline]
+fn format_bound(value: f64, integer: bool) -> String {
+    if integer {
+        format!("{}", value)
+    } else {
+        format!("{:?}", value)
+    }
+}
+
+/// Typ parametru konfiguracji opisany w JSON Schema (w postaci, w jakiej parametr jest
+/// zapisywany w plikach JSON, YAML i TOML).
+pub trait Schema {
+    /// Schemat wartości typu.
+    fn schema() -> Value;
+}
+
+impl Schema for u8 {
+    #[inline]
+    fn schema() -> Value {
+        json!({ "type": "integer", "minimum": 0, "maximum": u8::MAX })
+    }
+}
+
+impl Schema for u32 {
+    #[inline]
+    fn schema() -> Value {
+        json!({ "type": "integer", "minimum": 0, "maximum": u32::MAX })
+    }
+}
+
+impl Schema for u64 {
+    #[inline]
+    fn schema() -> Value {
+        json!({ "type": "integer", "minimum": 0 })
+    }
+}
+
+impl Schema for f32 {
+    #[inline]
+    fn schema() -> Value {
+        json!({ "type": "number" })
+    }
+}
+
+impl Schema for bool {
+    #[inline]
+    fn schema() -> Value {
+        json!({ "type": "boolean" })
+    }
+}
+
+impl Schema for String {
+    #[inline]
+    fn schema() -> Value {
+        json!({ "type": "string" })
+    }
+}
+
+impl<T: Schema> Schema for Option<T> {
+    #[inline]
+    fn schema() -> Value {
+        json!({ "anyOf": [T::schema(), { "type": "null" }] })
+    }
+}
+
+impl<T: Schema> Schema for Vec<T> {
+    #[inline]
+    fn schema() -> Value {
+        json!({ "type": "array", "items": T::schema() })
+    }
+}
+
+/// Implementacja schematu dla krotek (tablice o stałej długości).
+macro_rules! tuple_schema {
+    ($length:literal: $($element:ident),*) => {
+        impl<$($element: Schema),*> Schema for ($($element,)*) {
+            #[inline]
+            fn schema() -> Value {
+                json!({
+                    "type": "array",
+                    "items": [$($element::schema()),*],
+                    "additionalItems": false,
+                    "minItems": $length,
+                })
+            }
+        }
+    };
+}
+
+tuple_schema!(2: A, B);
+tuple_schema!(3: A, B, C);
+tuple_schema!(4: A, B, C, D);
+
+/// Schemat wyliczenia o wariantach bez parametrów.
+#[inline]
+fn unit_variants(variants: &[&str]) -> Value {
+    json!({ "type": "string", "enum": variants })
+}
+
+impl Schema for OutputFormat {
+    #[inline]
+    fn schema() -> Value {
+        unit_variants(&["Gif", "Apng", "WebP", "PngSequence", "Y4m", "Pipe"])
+    }
+}
+
+impl Schema for ManagementAction {
+    #[inline]
+    fn schema() -> Value {
+        unit_variants(&["ControlledBurn", "ClearCut"])
+    }
+}
+
+impl Schema for ManagementStrategy {
+    #[inline]
+    fn schema() -> Value {
+        unit_variants(&["RandomPatches", "GridPattern", "OldestFirst"])
+    }
+}
+
+impl Schema for FirefightingStrategy {
+    #[inline]
+    fn schema() -> Value {
+        json!({
+            "oneOf": [
+                unit_variants(&["Nearest", "Perimeter"]),
+                {
+                    "type": "object",
+                    "properties": {
+                        "ProtectArea": {
+                            "type": "object",
+                            "properties": { "x": u32::schema(), "y": u32::schema() },
+                            "required": ["x", "y"],
+                            "additionalProperties": false,
+                        },
+                    },
+                    "required": ["ProtectArea"],
+                    "additionalProperties": false,
+                },
+            ],
+        })
+    }
+}
+
+impl Schema for Asset {
+    #[inline]
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": String::schema(),
+                "color": <(u8, u8, u8)>::schema(),
+                "rectangles": <Vec<(u32, u32, u32, u32)>>::schema(),
+                "mask_path": <Option<String>>::schema(),
+            },
+            "required": ["name", "color"],
+            "additionalProperties": false,
+        })
+    }
+}
+
+/// Definicja parametru konfiguracji (generowana razem ze strukturą `Configuration`).
+#[derive(Debug, Clone, Copy)]
+pub struct ParameterDefinition {
+    /// Nazwa parametru.
+    pub name: &'static str,
+
+    /// Typ parametru (w zapisie języka Rust).
+    pub type_name: &'static str,
+
+    /// Schemat wartości parametru.
+    pub schema: fn() -> Value,
+
+    /// Dopuszczalne zakresy wartości: jeden dla parametrów liczbowych, po jednym dla każdego
+    /// elementu krotki, brak dla parametrów bez ograniczeń.
+    pub bounds: &'static [Bounds],
+
+    /// Opis parametru: (angielski, polski).
+    pub description: (&'static str, &'static str),
+}
+
+impl ParameterDefinition {
+    /// Czy parametr jest liczbą całkowitą (lub opcjonalną liczbą całkowitą).
+    #[inline]
+    pub fn integer(&self) -> bool {
+        let schema = (self.schema)();
+
+        schema["type"] == "integer" || schema["anyOf"][0]["type"] == "integer"
+    }
+
+    /// Sprawdzenie, czy wartość parametru (w postaci JSON) należy do dopuszczalnych zakresów.
+    /// Wartości f32 spoza zakresu liczb JSON (NaN, nieskończoność) są zapisywane jako `null`
+    /// i nie należą do żadnego zakresu, a brak wartości parametrów opcjonalnych jest poprawny.
+    #[inline]
+    pub fn check_bounds(&self, value: &Value) -> Option<Violation> {
+        let integer = !self.type_name.contains("f32");
+        let optional = (self.schema)().get("anyOf").is_some();
+        let elements = match value {
+            Value::Null if optional => return None,
+            Value::Array(elements) => elements.as_slice(),
+            value => std::slice::from_ref(value),
+        };
+
+        let in_bounds = self
+            .bounds
+            .iter()
+            .zip(elements)
+            .all(|(bounds, element)| bounds.contains(element.as_f64().unwrap_or(f64::NAN)));
+
+        if in_bounds {
+            return None;
+        }
+
+        let number = |element: &Value| match element {
+            Value::Null => format!("{:?}", f64::NAN),
+            element => element.to_string(),
+        };
+
+        Some(match self.bounds {
+            [bounds] => Violation::new(self.name, number(value), bounds.constraint(integer)),
+            bounds => Violation::new(
+                self.name,
+                format!(
+                    "({})",
+                    elements.iter().map(number).collect::<Vec<_>>().join(", ")
+                ),
+                tr!(
+                    "in the range ({})",
+                    "wartości z zakresu ({})",
+                    bounds
+                        .iter()
+                        .map(|bounds| bounds.describe(integer))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            ),
+        })
+    }
+}
+
+/// Parametr konfiguracji wraz z wartością domyślną.
+#[derive(Debug, Clone)]
+pub struct Parameter {
+    /// Definicja parametru.
+    pub definition: &'static ParameterDefinition,
+
+    /// Wartość domyślna (w postaci JSON), brak dla parametrów wymaganych.
+    pub default: Option<Value>,
+}
+
+impl Parameter {
+    /// Czy parametr jest wymagany.
+    #[inline]
+    pub fn required(&self) -> bool {
+        self.default.is_none()
+    }
+
+    /// Opis parametru w aktualnym języku.
+    #[inline]
+    pub fn description(&self) -> &'static str {
+        text(self.definition.description.0, self.definition.description.1)
+    }
+
+    /// Schemat wartości parametru wraz z dopuszczalnymi zakresami.
+    #[inline]
+    pub fn schema(&self) -> Value {
+        let mut schema = (self.definition.schema)();
+
+        match (self.definition.bounds, schema.get_mut("items")) {
+            ([bounds], None) => bounds.restrict(&mut schema),
+            (bounds, Some(Value::Array(elements))) => {
+                for (bounds, element) in bounds.iter().zip(elements) {
+                    bounds.restrict(element);
+                }
+            }
+            _ => {}
+        }
+
+        schema
+    }
+
+    /// Zapis dopuszczalnych zakresów wartości (pusty dla parametrów bez ograniczeń).
+    #[inline]
+    pub fn describe_bounds(&self) -> String {
+        let integer = !self.definition.type_name.contains("f32");
+        let bounds: Vec<String> = self
+            .definition
+            .bounds
+            .iter()
+            .map(|bounds| bounds.describe(integer))
+            .collect();
+
+        match bounds.len() {
+            0 | 1 => bounds.concat(),
+            _ => format!("({})", bounds.join(", ")),
+        }
+    }
+}
+
+/// Konfiguracja w postaci wartości JSON. Zapis przez tekst zachowuje najkrótszą postać liczb
+/// f32 (0.005 zamiast 0.004999999888241291).
+#[inline]
+pub(crate) fn as_value(config: &Configuration) -> Option<Value> {
+    serde_json::to_string(config)
+        .ok()
+        .and_then(|text| serde_json::from_str(&text).ok())
+}
+
+/// Parametry konfiguracji (w kolejności pól struktury `Configuration`). Wartości domyślne
+/// i parametry wymagane są ustalane na podstawie deserializacji konfiguracji, w której
+/// pominięto dany parametr.
+#[inline]
+pub fn parameters() -> Vec<Parameter> {
+    let complete = preset("green_tea")
+        .and_then(|text| parse_configuration(text).ok())
+        .and_then(|config| as_value(&config))
+        .unwrap_or_default();
+
+    PARAMETERS
+        .iter()
+        .map(|definition| {
+            let mut fields = complete.as_object().cloned().unwrap_or_default();
+            fields.remove(definition.name);
+
+            let default = serde_json::from_value::<Configuration>(Value::Object(fields))
+                .ok()
+                .and_then(|config| as_value(&config))
+                // Parametry pomijane przy zapisie (`None`) mają wartość domyślną `null`
+                .map(|config| config.get(definition.name).cloned().unwrap_or(Value::Null));
+
+            Parameter {
+                definition,
+                default,
+            }
+        })
+        .collect()
+}
+
+/// Schemat pliku konfiguracyjnego w formacie JSON Schema (opisy w aktualnym języku).
+#[inline]
+pub fn json_schema() -> Value {
+    let parameters = parameters();
+    let presets: Vec<&str> = PRESETS.iter().map(|(name, _)| *name).collect();
+
+    let mut properties = Map::new();
+    properties.insert(
+        "extends".to_string(),
+        json!({
+            "description": text(
+                "Preset or configuration file whose parameters are inherited",
+                "Preset lub plik konfiguracyjny, z którego dziedziczone są parametry",
+            ),
+            "anyOf": [{ "enum": presets }, { "type": "string" }],
+        }),
+    );
+
+    for parameter in &parameters {
+        let mut schema = json!({ "description": parameter.description() });
+        schema
+            .as_object_mut()
+            .unwrap()
+            .extend(parameter.schema().as_object().cloned().unwrap_or_default());
+
+        if let Some(default) = &parameter.default {
+            schema["default"] = default.clone();
+        }
+
+        properties.insert(parameter.definition.name.to_string(), schema);
+    }
+
+    let required: Vec<&str> = parameters
+        .iter()
+        .filter(|parameter| parameter.required())
+        .map(|parameter| parameter.definition.name)
+        .collect();
+
+    json!({
+        "$schema": META_SCHEMA,
+        "title": "Configuration",
+        "description": text(
+            "Configuration of the forest fire simulation",
+            "Konfiguracja symulacji pożaru lasu",
+        ),
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
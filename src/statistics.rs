@@ -0,0 +1,412 @@
+use crate::{assets::AssetReport, configuration::Configuration, simulation::Cell};
+
+#[cfg(feature = "cli")]
+use crate::{locale::text, tr};
+
+#[cfg(feature = "cli")]
+use console::style;
+
+/// Nazwy statystyk zbiorczych (kolumny tabel podsumowujących).
+pub const SUMMARY_COLUMNS: [&str; 13] = [
+    "steps",
+    "alive_cells",
+    "snag_cells",
+    "on_fire_cells",
+    "dead_cells",
+    "ignitions",
+    "fires",
+    "mean_fire_size",
+    "max_fire_size",
+    "peak_on_fire_cells",
+    "mean_alive_cells",
+    "extinguished_cells",
+    "treated_cells",
+];
+
+/// Wartości statystyk zbiorczych.
+pub type Summary = [f64; SUMMARY_COLUMNS.len()];
+
+/// Statystyki zebrane w trakcie symulacji.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Statistics {
+    /// Liczba wykonanych kroków symulacji.
+    pub steps: u32,
+
+    /// Liczba żywych drzew po ostatnim kroku.
+    pub alive_cells: usize,
+
+    /// Liczba komórek posuszu po ostatnim kroku.
+    pub snag_cells: usize,
+
+    /// Liczba płonących komórek po ostatnim kroku.
+    pub on_fire_cells: usize,
+
+    /// Liczba pustych komórek po ostatnim kroku.
+    pub dead_cells: usize,
+
+    /// Łączna liczba zapłonów.
+    pub ignitions: u64,
+
+    /// Największa liczba jednocześnie płonących komórek.
+    pub peak_on_fire_cells: usize,
+
+    /// Średnia liczba żywych drzew w trakcie symulacji.
+    pub mean_alive_cells: f64,
+
+    /// Liczba jednostek straży pożarnej.
+    pub firefighters: u32,
+
+    /// Łączna liczba komórek ugaszonych przez jednostki straży pożarnej.
+    pub extinguished_cells: u64,
+
+    /// Łączna liczba komórek, z których usunięto drzewa w ramach zarządzania lasem.
+    pub treated_cells: u64,
+
+    /// Wielkości kolejnych pożarów (liczba komórek objętych danym pożarem).
+    pub fire_sizes: Vec<u64>,
+
+    /// Identyfikatory pożarów przypisane komórkom w chwili zapłonu (0 - brak pożaru).
+    fire_labels: Vec<u32>,
+
+    /// Raporty o uszkodzeniach chronionych obiektów.
+    pub assets: Vec<AssetReport>,
+}
+
+impl Statistics {
+    /// Utworzenie pustych statystyk dla zadanej konfiguracji.
+    #[inline]
+    pub fn new(config: &Configuration) -> Self {
+        let cells_x = config.resolution.0 / config.cell_size + 2;
+        let cells_y = config.resolution.1 / config.cell_size + 2;
+
+        Self {
+            firefighters: config.firefighter_count,
+            fire_labels: vec![0; (cells_x * cells_y) as usize],
+            ..Self::default()
+        }
+    }
+
+    /// Liczba pożarów.
+    #[inline]
+    pub fn fires(&self) -> usize {
+        self.fire_sizes.len()
+    }
+
+    /// Średnia wielkość pożaru.
+    #[inline]
+    pub fn mean_fire_size(&self) -> f64 {
+        self.fire_sizes.iter().sum::<u64>() as f64 / self.fire_sizes.len().max(1) as f64
+    }
+
+    /// Wielkość największego pożaru.
+    #[inline]
+    pub fn max_fire_size(&self) -> u64 {
+        self.fire_sizes.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Mapa komórek objętych ogniem w trakcie symulacji (bez obramowania, wierszami).
+    #[inline]
+    pub fn burn_map(&self, config: &Configuration) -> Vec<bool> {
+        let cells_x = (config.resolution.0 / config.cell_size) as usize;
+        let cells_y = (config.resolution.1 / config.cell_size) as usize;
+
+        (1..=cells_y)
+            .flat_map(|index_y| {
+                (1..=cells_x)
+                    .map(move |index_x| self.fire_labels[index_y * (cells_x + 2) + index_x] > 0)
+            })
+            .collect()
+    }
+
+    /// Wartości statystyk zbiorczych (w kolejności `SUMMARY_COLUMNS`).
+    #[inline]
+    pub fn summary(&self) -> Summary {
+        self.summary_with(self.extinguished_cells, self.treated_cells)
+    }
+
+    /// Wartości statystyk zbiorczych z liczbami komórek ugaszonych i objętych zarządzaniem
+    /// lasem podanymi przez podsystemy symulacji.
+    #[inline]
+    pub(crate) fn summary_with(&self, extinguished_cells: u64, treated_cells: u64) -> Summary {
+        [
+            self.steps as f64,
+            self.alive_cells as f64,
+            self.snag_cells as f64,
+            self.on_fire_cells as f64,
+            self.dead_cells as f64,
+            self.ignitions as f64,
+            self.fires() as f64,
+            self.mean_fire_size(),
+            self.max_fire_size() as f64,
+            self.peak_on_fire_cells as f64,
+            self.mean_alive_cells,
+            extinguished_cells as f64,
+            treated_cells as f64,
+        ]
+    }
+
+    /// Aktualizacja statystyk po wykonaniu kroku symulacji.
+    #[inline]
+    pub fn update(
+        &mut self,
+        config: &Configuration,
+        previous_state: &[Cell],
+        current_state: &[Cell],
+    ) {
+        let cells_x = (config.resolution.0 / config.cell_size) as usize + 2;
+        let cells_y = (config.resolution.1 / config.cell_size) as usize + 2;
+
+        self.alive_cells = 0;
+        self.snag_cells = 0;
+        self.on_fire_cells = 0;
+        self.dead_cells = 0;
+
+        for index_y in 1..cells_y - 1 {
+            for index_x in 1..cells_x - 1 {
+                let index = index_y * cells_x + index_x;
+
+                match current_state[index] {
+                    Cell::Alive { .. } => self.alive_cells += 1,
+                    Cell::Snag { .. } => self.snag_cells += 1,
+                    Cell::OnFire { .. } => {
+                        self.on_fire_cells += 1;
+
+                        // Zapłon: komórka nie płonęła w poprzednim kroku
+                        if !matches!(previous_state[index], Cell::OnFire { .. }) {
+                            self.ignitions += 1;
+                            self.register_ignition(previous_state, cells_x, index);
+                        }
+                    }
+                    Cell::Dead => self.dead_cells += 1,
+                }
+            }
+        }
+
+        self.steps += 1;
+        self.peak_on_fire_cells = self.peak_on_fire_cells.max(self.on_fire_cells);
+        self.mean_alive_cells +=
+            (self.alive_cells as f64 - self.mean_alive_cells) / self.steps as f64;
+    }
+
+    /// Zapłon wywołany poza symulacją (np. przez użytkownika), rozpoczyna nowy pożar.
+    #[inline]
+    pub fn register_external_ignition(&mut self, index: usize) {
+        self.ignitions += 1;
+        self.fire_sizes.push(1);
+        self.fire_labels[index] = self.fire_sizes.len() as u32;
+    }
+
+    /// Wypalanie kontrolowane komórek (poza symulacją), rozpoczyna jeden nowy pożar
+    /// obejmujący wszystkie podpalone komórki.
+    #[inline]
+    pub fn register_controlled_burn(&mut self, indices: &[usize]) {
+        self.ignitions += indices.len() as u64;
+        self.fire_sizes.push(indices.len() as u64);
+
+        for &index in indices {
+            self.fire_labels[index] = self.fire_sizes.len() as u32;
+        }
+    }
+
+    /// Przypisanie zapłonu do pożaru: ogień przeniesiony od płonącego sąsiada należy do
+    /// jego pożaru, w przeciwnym razie (samozapłon) rozpoczyna się nowy pożar.
+    #[inline]
+    fn register_ignition(&mut self, previous_state: &[Cell], cells_x: usize, index: usize) {
+        let label = [
+            index - cells_x - 1,
+            index - cells_x,
+            index - cells_x + 1,
+            index - 1,
+            index + 1,
+            index + cells_x - 1,
+            index + cells_x,
+            index + cells_x + 1,
+        ]
+        .into_iter()
+        .find(|&neighbour| matches!(previous_state[neighbour], Cell::OnFire { .. }))
+        .map(|neighbour| self.fire_labels[neighbour])
+        .filter(|&label| label > 0);
+
+        let label = match label {
+            Some(label) => label,
+            None => {
+                self.fire_sizes.push(0);
+                self.fire_sizes.len() as u32
+            }
+        };
+
+        self.fire_labels[index] = label;
+        self.fire_sizes[label as usize - 1] += 1;
+    }
+}
+
+/// Funkcja wypisująca statystyki symulacji.
+#[cfg(feature = "cli")]
+#[inline]
+pub fn print_statistics(statistics: &Statistics) {
+    println!(
+        "\n{}:\n\
+            \n    {} {}\
+            \n    {} {}\
+            \n    {} {}\
+            \n    {} {}\
+            \n    {} {}\
+            \n    {} {}\
+            \n    {} {}\
+            \n    {} {:.1}",
+        style(text("Simulation statistics", "Statystyki symulacji"))
+            .blue()
+            .bold(),
+        style(text("Number of steps:", "Liczba kroków:")).green(),
+        style(statistics.steps).bold(),
+        style(text("Alive trees:", "Żywe drzewa:")).green(),
+        style(statistics.alive_cells).bold(),
+        style(text("Snags:", "Posusz:")).green(),
+        style(statistics.snag_cells).bold(),
+        style(text("Burning trees:", "Płonące drzewa:")).green(),
+        style(statistics.on_fire_cells).bold(),
+        style(text("Empty cells:", "Puste komórki:")).green(),
+        style(statistics.dead_cells).bold(),
+        style(text("Number of ignitions:", "Liczba zapłonów:")).green(),
+        style(statistics.ignitions).bold(),
+        style(text(
+            "Peak number of burning trees:",
+            "Maksymalna liczba płonących drzew:"
+        ))
+        .green(),
+        style(statistics.peak_on_fire_cells).bold(),
+        style(text(
+            "Mean number of alive trees:",
+            "Średnia liczba żywych drzew:"
+        ))
+        .green(),
+        style(statistics.mean_alive_cells).bold()
+    );
+
+    println!(
+        "    {} {}\
+        \n    {} {:.1}\
+        \n    {} {}",
+        style(text("Number of fires:", "Liczba pożarów:")).green(),
+        style(statistics.fires()).bold(),
+        style(text("Mean fire size:", "Średnia wielkość pożaru:")).green(),
+        style(statistics.mean_fire_size()).bold(),
+        style(text("Largest fire:", "Największy pożar:")).green(),
+        style(statistics.max_fire_size()).bold()
+    );
+
+    // Zarządzanie lasem
+    if statistics.treated_cells > 0 {
+        println!(
+            "    {} {}",
+            style(text("Treated cells:", "Komórki poddane zabiegom:")).green(),
+            style(statistics.treated_cells).bold()
+        );
+    }
+
+    // Skuteczność jednostek straży pożarnej
+    if statistics.firefighters > 0 {
+        println!(
+            "    {} {}\
+            \n    {} {:.1}",
+            style(text("Extinguished cells:", "Ugaszone komórki:")).green(),
+            style(statistics.extinguished_cells).bold(),
+            style(text(
+                "Extinguished cells per unit:",
+                "Ugaszone komórki na jednostkę:"
+            ))
+            .green(),
+            style(statistics.extinguished_cells as f64 / statistics.firefighters as f64).bold()
+        );
+    }
+
+    // Uszkodzenia chronionych obiektów
+    for asset in statistics.assets.iter() {
+        println!(
+            "    {} {} {} {}/{} ({:.1}%)",
+            style(tr!("Asset \"{}\":", "Obiekt \"{}\":", asset.name)).green(),
+            style(match asset.first_impact_step {
+                Some(step) => tr!("fire arrived at step {},", "ogień dotarł w kroku {},", step),
+                None => text("fire did not arrive,", "ogień nie dotarł,").to_string(),
+            })
+            .bold(),
+            style(text("damaged area:", "uszkodzona powierzchnia:")).green(),
+            style(asset.damaged_area).bold(),
+            asset.area,
+            100.0 * asset.damaged_area as f64 / asset.area.max(1) as f64
+        );
+    }
+}
+
+/// Funkcja wypisująca porównanie statystyk pożarów symulacji z zarządzaniem lasem
+/// i bez zarządzania.
+#[cfg(feature = "cli")]
+#[inline]
+pub fn print_management_comparison(managed: &Statistics, unmanaged: &Statistics) {
+    let rows: [(&str, f64, f64); 6] = [
+        (
+            text("Number of fires:", "Liczba pożarów:"),
+            managed.fires() as f64,
+            unmanaged.fires() as f64,
+        ),
+        (
+            text("Mean fire size:", "Średnia wielkość pożaru:"),
+            managed.mean_fire_size(),
+            unmanaged.mean_fire_size(),
+        ),
+        (
+            text("Largest fire:", "Największy pożar:"),
+            managed.max_fire_size() as f64,
+            unmanaged.max_fire_size() as f64,
+        ),
+        (
+            text("Number of ignitions:", "Liczba zapłonów:"),
+            managed.ignitions as f64,
+            unmanaged.ignitions as f64,
+        ),
+        (
+            text(
+                "Peak number of burning trees:",
+                "Maksymalna liczba płonących drzew:",
+            ),
+            managed.peak_on_fire_cells as f64,
+            unmanaged.peak_on_fire_cells as f64,
+        ),
+        (
+            text(
+                "Mean number of alive trees:",
+                "Średnia liczba żywych drzew:",
+            ),
+            managed.mean_alive_cells,
+            unmanaged.mean_alive_cells,
+        ),
+    ];
+
+    println!(
+        "\n{} ({} / {}):\n",
+        style(text(
+            "Impact of forest management",
+            "Wpływ zarządzania lasem"
+        ))
+        .blue()
+        .bold(),
+        style(text("with management", "z zarządzaniem")).bold(),
+        style(text("without management", "bez zarządzania")).bold()
+    );
+
+    for (label, managed, unmanaged) in rows {
+        let change = if unmanaged != 0.0 {
+            format!("{:+.1}%", 100.0 * (managed - unmanaged) / unmanaged)
+        } else {
+            "-".to_string()
+        };
+
+        println!(
+            "    {:<36} {:>12.1} / {:<12.1} {}",
+            style(label).green(),
+            managed,
+            unmanaged,
+            style(change).bold()
+        );
+    }
+}
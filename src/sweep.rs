@@ -0,0 +1,363 @@
+use crate::{
+    configuration::{override_configuration, validate_configuration, Configuration, PARAMETERS},
+    error::{ForestFireError, Operation},
+    locale::text,
+    simulation::simulate_parallel,
+    statistics::{Summary, SUMMARY_COLUMNS},
+    tr,
+};
+
+use console::style;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use serde_json::Value;
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Wartości przeszukiwanego parametru.
+enum SweepValues {
+    /// Zakres liczbowy: początek, koniec (włącznie) i liczba wartości.
+    Range { start: f64, end: f64, count: usize },
+
+    /// Lista wartości.
+    List(Vec<Value>),
+}
+
+/// Parametr konfiguracji przeszukiwany w ramach przeglądu.
+struct SweepParameter {
+    name: String,
+    values: SweepValues,
+
+    /// Czy parametr jest całkowitoliczbowy (wartości z zakresu są wtedy zaokrąglane).
+    integral: bool,
+}
+
+/// Pojedynczy przebieg przeglądu parametrów.
+struct SweepRun {
+    values: Vec<Value>,
+    config: Configuration,
+}
+
+/// Parsowanie opisu parametru w postaci `parametr=początek:koniec:liczba` (zakres),
+/// `parametr=początek:koniec` (zakres z dwiema wartościami skrajnymi) lub
+/// `parametr=[wartość, ...]` (lista w składni JSON).
+#[inline]
+fn parse_sweep_parameter(specification: &str) -> Result<SweepParameter, ForestFireError> {
+    let error = || {
+        ForestFireError::parse(
+            Operation::ParseSweepParameter,
+            None,
+            tr!(
+                "sweep parameter \"{}\" must have the form \
+                parameter=start:end:count or parameter=[value, ...]",
+                "parametr przeglądu \"{}\" musi mieć postać \
+                parametr=początek:koniec:liczba lub parametr=[wartość, ...]",
+                specification
+            ),
+        )
+    };
+
+    let (name, raw_values) = specification.split_once('=').ok_or_else(error)?;
+    let (name, raw_values) = (name.trim().to_string(), raw_values.trim());
+
+    // O zaokrąglaniu wartości decyduje typ parametru, a nie granice zakresu
+    let integral = PARAMETERS
+        .iter()
+        .any(|definition| definition.name == name && definition.integer());
+
+    if let Ok(Value::Array(values)) = serde_json::from_str(raw_values) {
+        if values.is_empty() {
+            return Err(error());
+        }
+
+        return Ok(SweepParameter {
+            name,
+            values: SweepValues::List(values),
+            integral,
+        });
+    }
+
+    let parts: Vec<&str> = raw_values.split(':').collect();
+
+    match parts.as_slice() {
+        [start, end, count] => match (start.parse(), end.parse(), count.parse()) {
+            (Ok(start), Ok(end), Ok(count)) if count > 0 => Ok(SweepParameter {
+                name,
+                values: SweepValues::Range { start, end, count },
+                integral,
+            }),
+            _ => Err(error()),
+        },
+        [start, end] => match (start.parse(), end.parse()) {
+            (Ok(start), Ok(end)) => Ok(SweepParameter {
+                name,
+                values: SweepValues::Range {
+                    start,
+                    end,
+                    count: 2,
+                },
+                integral,
+            }),
+            _ => Err(error()),
+        },
+        _ => Err(error()),
+    }
+}
+
+/// Wartość liczbowa w postaci JSON (całkowita, jeżeli parametr jest całkowitoliczbowy).
+#[inline]
+fn number_value(value: f64, integral: bool) -> Value {
+    if integral {
+        Value::from(value.round() as i64)
+    } else {
+        Value::from(value)
+    }
+}
+
+impl SweepParameter {
+    /// Wszystkie wartości parametru (dla iloczynu kartezjańskiego).
+    #[inline]
+    fn grid_values(&self) -> Vec<Value> {
+        match &self.values {
+            SweepValues::Range { start, end, count } => (0..*count)
+                .map(|index| {
+                    let fraction = if *count > 1 {
+                        index as f64 / (*count - 1) as f64
+                    } else {
+                        0.0
+                    };
+
+                    number_value(start + (end - start) * fraction, self.integral)
+                })
+                .collect(),
+            SweepValues::List(values) => values.clone(),
+        }
+    }
+
+    /// Wartość parametru odpowiadająca kwantylowi `fraction` z przedziału [0, 1).
+    #[inline]
+    fn sample_value(&self, fraction: f64) -> Value {
+        match &self.values {
+            SweepValues::Range { start, end, .. } => {
+                number_value(start + (end - start) * fraction, self.integral)
+            }
+            SweepValues::List(values) => {
+                values[((fraction * values.len() as f64) as usize).min(values.len() - 1)].clone()
+            }
+        }
+    }
+}
+
+/// Iloczyn kartezjański wartości parametrów.
+#[inline]
+fn cartesian_product(parameters: &[SweepParameter]) -> Vec<Vec<Value>> {
+    parameters
+        .iter()
+        .fold(vec![Vec::new()], |combinations, parameter| {
+            combinations
+                .iter()
+                .flat_map(|combination| {
+                    parameter.grid_values().into_iter().map(move |value| {
+                        let mut combination = combination.clone();
+                        combination.push(value);
+                        combination
+                    })
+                })
+                .collect()
+        })
+}
+
+/// Próbkowanie metodą łacińskiego hipersześcianu: zakres każdego parametru jest dzielony
+/// na `samples` równych przedziałów, z których każdy jest wykorzystany dokładnie raz.
+#[inline]
+fn latin_hypercube(
+    parameters: &[SweepParameter],
+    samples: usize,
+    rng: &mut StdRng,
+) -> Vec<Vec<Value>> {
+    let mut combinations = vec![Vec::with_capacity(parameters.len()); samples];
+
+    for parameter in parameters {
+        let mut strata: Vec<usize> = (0..samples).collect();
+        strata.shuffle(rng);
+
+        for (combination, stratum) in combinations.iter_mut().zip(strata) {
+            let fraction = (stratum as f64 + rng.gen::<f64>()) / samples as f64;
+            combination.push(parameter.sample_value(fraction));
+        }
+    }
+
+    combinations
+}
+
+/// Przegląd parametrów: symulacje dla wszystkich kombinacji wartości parametrów (lub próbek
+/// łacińskiego hipersześcianu) wykonywane równolegle, z zapisem tabeli statystyk do pliku CSV.
+/// Przegląd bez parametrów lub z zerową liczbą próbek jest błędem walidacji.
+#[inline]
+pub fn sweep(
+    config: &Configuration,
+    specifications: &[String],
+    samples: Option<usize>,
+    jobs: usize,
+    summary_path: &str,
+) -> Result<(), ForestFireError> {
+    if specifications.is_empty() {
+        return Err(ForestFireError::validation(
+            "param",
+            "[]",
+            text(
+                "at least one swept parameter",
+                "co najmniej jeden przeszukiwany parametr",
+            ),
+        ));
+    }
+
+    if samples == Some(0) {
+        return Err(ForestFireError::validation(
+            "samples",
+            0,
+            text("greater than 0", "wartości większe od 0"),
+        ));
+    }
+
+    let parameters = specifications
+        .iter()
+        .map(|specification| parse_sweep_parameter(specification))
+        .collect::<Result<Vec<SweepParameter>, ForestFireError>>()?;
+
+    let base_seed = config.seed.unwrap_or(0);
+    let mut rng = StdRng::seed_from_u64(base_seed);
+
+    let combinations = match samples {
+        Some(samples) => latin_hypercube(&parameters, samples, &mut rng),
+        None => cartesian_product(&parameters),
+    };
+
+    // Przygotowanie i walidacja konfiguracji wszystkich przebiegów (każdy z własnym ziarnem)
+    let mut runs = Vec::with_capacity(combinations.len());
+
+    for (index, values) in combinations.into_iter().enumerate() {
+        let mut overrides: Vec<String> = parameters
+            .iter()
+            .zip(values.iter())
+            .map(|(parameter, value)| format!("{}={}", parameter.name, value))
+            .collect();
+        overrides.push(format!("seed={}", base_seed.wrapping_add(index as u64)));
+
+        let run_config = override_configuration(config, &overrides)?;
+        validate_configuration(&run_config)?;
+
+        runs.push(SweepRun {
+            values,
+            config: run_config,
+        });
+    }
+
+    // Równoległe wykonanie przebiegów
+    let configs: Vec<Configuration> = runs.iter().map(|run| run.config.clone()).collect();
+    let results: Mutex<Vec<Option<Result<Summary, ForestFireError>>>> =
+        Mutex::new((0..runs.len()).map(|_| None).collect());
+
+    simulate_parallel(
+        &configs,
+        jobs,
+        text("Parameter sweep progress:", "Postęp przeglądu parametrów:"),
+        |index, result| {
+            results.lock().unwrap()[index] = Some(result.map(|statistics| statistics.summary()));
+        },
+    );
+
+    // Zebranie wyników wszystkich przebiegów przed zapisem tabeli: błąd dowolnego przebiegu
+    // przerywa przegląd bez tworzenia niepełnej tabeli, a nieudane kombinacje są wypisywane
+    let mut summaries = Vec::with_capacity(runs.len());
+    let mut first_error = None;
+
+    for (index, (run, result)) in runs.iter().zip(results.into_inner().unwrap()).enumerate() {
+        match result.unwrap() {
+            Ok(summary) => summaries.push(summary),
+            Err(error) => {
+                let combination: Vec<String> = parameters
+                    .iter()
+                    .zip(run.values.iter())
+                    .map(|(parameter, value)| format!("{}={}", parameter.name, value))
+                    .chain([format!("seed={}", run.config.seed.unwrap())])
+                    .collect();
+
+                eprintln!(
+                    "{} {}",
+                    style(tr!(
+                        "Run {} failed:",
+                        "Przebieg {} zakończył się błędem:",
+                        index
+                    ))
+                    .red()
+                    .bold(),
+                    combination.join(", ")
+                );
+
+                first_error.get_or_insert(error);
+            }
+        }
+    }
+
+    if let Some(error) = first_error {
+        return Err(error);
+    }
+
+    // Zapis tabeli podsumowującej
+    let write_error =
+        |error: std::io::Error| ForestFireError::io(Operation::SaveSummary, summary_path, error);
+
+    let mut writer = BufWriter::new(File::create(Path::new(summary_path)).map_err(write_error)?);
+
+    let header: Vec<&str> = ["run", "seed"]
+        .into_iter()
+        .chain(parameters.iter().map(|parameter| parameter.name.as_str()))
+        .chain(SUMMARY_COLUMNS)
+        .collect();
+    writeln!(writer, "{}", header.join(",")).map_err(write_error)?;
+
+    for (index, (run, summary)) in runs.iter().zip(summaries).enumerate() {
+        let row: Vec<String> = [index.to_string(), run.config.seed.unwrap().to_string()]
+            .into_iter()
+            .chain(run.values.iter().map(|value| match value {
+                Value::String(text) => csv_field(text),
+                _ => csv_field(&value.to_string()),
+            }))
+            .chain(summary.iter().map(|value| value.to_string()))
+            .collect();
+        writeln!(writer, "{}", row.join(",")).map_err(write_error)?;
+    }
+
+    writer.flush().map_err(write_error)?;
+
+    println!(
+        "{} {} ({} {})",
+        style(text(
+            "Saved the summary table:",
+            "Zapisano tabelę podsumowującą:"
+        ))
+        .green()
+        .bold(),
+        style(format!("\"{}\"", summary_path))
+            .cyan()
+            .italic()
+            .bold(),
+        runs.len(),
+        style(text("runs", "przebiegów")).bold()
+    );
+
+    Ok(())
+}
+
+/// Pole tabeli CSV (ujęte w cudzysłów, jeżeli zawiera przecinki lub cudzysłowy).
+#[inline]
+fn csv_field(field: &str) -> String {
+    if field.contains(',') || field.contains('"') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -1,4 +1,10 @@
-use crate::{configuration::Configuration, simulation::Cell};
+use crate::{
+    configuration::Configuration,
+    simulation::{Cell, BASE_TREE_SIZE},
+};
+
+/// Minimalna jasność płonącego drzewa i posuszu.
+const MIN_SHADE: f32 = 0.3;
 
 /// Funkcja rysująca krok symulacji do bufora klatki.
 #[inline]
@@ -21,19 +27,28 @@ pub fn draw(config: &Configuration, state: &[Cell], frame_buffer: &mut [u8]) {
 
                     match state[(cell_index_y + 1) * cells_x + cell_index_x + 1] {
                         // Jasność komórki żywej jest zależna od rozmiaru drzewa
-                        Cell::Alive { size, color } => unsafe {
+                        Cell::Alive { size, color, .. } => unsafe {
                             *pixel.get_unchecked_mut(0) = (color.0 as f32 * size) as u8;
                             *pixel.get_unchecked_mut(1) = (color.1 as f32 * size) as u8;
                             *pixel.get_unchecked_mut(2) = (color.2 as f32 * size) as u8;
                         },
+                        // Jasność posuszu jest zależna od stopnia jego rozpadu
+                        Cell::Snag { integrity } => unsafe {
+                            *pixel.get_unchecked_mut(0) =
+                                (config.snag_color.0 as f32 * integrity.max(MIN_SHADE)) as u8;
+                            *pixel.get_unchecked_mut(1) =
+                                (config.snag_color.1 as f32 * integrity.max(MIN_SHADE)) as u8;
+                            *pixel.get_unchecked_mut(2) =
+                                (config.snag_color.2 as f32 * integrity.max(MIN_SHADE)) as u8;
+                        },
                         // Jasność płonącego drzewa jest zależna od postępu spalania
                         Cell::OnFire { progress } => unsafe {
                             *pixel.get_unchecked_mut(0) =
-                                (config.fire_color.0 as f32 * progress.max(0.3)) as u8;
+                                (config.fire_color.0 as f32 * progress.max(MIN_SHADE)) as u8;
                             *pixel.get_unchecked_mut(1) =
-                                (config.fire_color.1 as f32 * progress.max(0.3)) as u8;
+                                (config.fire_color.1 as f32 * progress.max(MIN_SHADE)) as u8;
                             *pixel.get_unchecked_mut(2) =
-                                (config.fire_color.2 as f32 * progress.max(0.3)) as u8;
+                                (config.fire_color.2 as f32 * progress.max(MIN_SHADE)) as u8;
                         },
                         // Komórka martwa ma kolor podłoża
                         Cell::Dead => unsafe {
@@ -45,3 +60,59 @@ pub fn draw(config: &Configuration, state: &[Cell], frame_buffer: &mut [u8]) {
                 })
         });
 }
+
+/// Przyciemnienie koloru o zadany współczynnik (tak jak w funkcji `draw`).
+#[inline]
+fn shade(color: (u8, u8, u8), factor: f32) -> (u8, u8, u8) {
+    (
+        (color.0 as f32 * factor) as u8,
+        (color.1 as f32 * factor) as u8,
+        (color.2 as f32 * factor) as u8,
+    )
+}
+
+/// Paleta kolorów występujących w klatkach animacji: kolor podłoża, kolor jednostek straży
+/// pożarnej, `levels` odcieni koloru ognia i posuszu, kolory chronionych obiektów oraz
+/// odcienie kolorów lasu. Paleta zawiera co najwyżej `max_colors` kolorów:
+/// kolory stałe, ognia i posuszu mają pierwszeństwo, a liczba odcieni kolorów lasu jest
+/// zmniejszana do pozostałego miejsca (przy bardzo długiej palecie lasu wybierane są
+/// równomiernie rozłożone kolory lasu w pełnej jasności).
+#[inline]
+pub fn palette(config: &Configuration, levels: u32, max_colors: usize) -> Vec<(u8, u8, u8)> {
+    let mut colors = vec![config.ground_color, config.firefighter_color];
+
+    // Odcienie kolorów, których jasność zależy od stanu komórki
+    let ramp = |color: (u8, u8, u8), min_factor: f32, levels: usize| {
+        (0..levels).map(move |level| {
+            let fraction = if levels > 1 {
+                level as f32 / (levels - 1) as f32
+            } else {
+                1.0
+            };
+
+            shade(color, min_factor + (1.0 - min_factor) * fraction)
+        })
+    };
+
+    colors.extend(ramp(config.fire_color, MIN_SHADE, levels as usize));
+    colors.extend(ramp(config.snag_color, MIN_SHADE, levels as usize));
+
+    colors.extend(config.assets.iter().map(|asset| asset.color));
+
+    // Odcienie kolorów lasu w pozostałej części palety
+    let forest = &config.forest_color_palette;
+    let budget = max_colors.saturating_sub(colors.len());
+    let forest_levels = (budget / forest.len().max(1)).min(levels as usize);
+
+    if forest_levels > 0 {
+        for &color in forest {
+            colors.extend(ramp(color, BASE_TREE_SIZE, forest_levels));
+        }
+    } else {
+        colors.extend((0..budget).map(|index| forest[index * forest.len() / budget]));
+    }
+
+    colors.dedup();
+    colors.truncate(max_colors);
+    colors
+}
@@ -0,0 +1,116 @@
+//! Interfejs dla JavaScriptu (WebAssembly): symulacja tworzona na podstawie konfiguracji
+//! w formacie JSON, wykonywana krok po kroku, z klatką obrazu w formacie RGBA udostępnianą
+//! bezpośrednio z pamięci modułu.
+
+use crate::{
+    configuration::{validate_configuration, Configuration},
+    simulation::Simulation,
+    statistics::SUMMARY_COLUMNS,
+};
+
+use wasm_bindgen::prelude::*;
+
+/// Symulacja udostępniana w JavaScripcie jako klasa `Simulation`.
+#[wasm_bindgen(js_name = Simulation)]
+pub struct WasmSimulation {
+    simulation: Simulation,
+
+    /// Bufor klatki w formacie RGB (rysowanie symulacji).
+    rgb_buffer: Vec<u8>,
+
+    /// Bufor klatki w formacie RGBA (udostępniany w JavaScripcie).
+    rgba_buffer: Vec<u8>,
+}
+
+#[wasm_bindgen(js_class = Simulation)]
+impl WasmSimulation {
+    /// Utworzenie symulacji na podstawie konfiguracji w formacie JSON (parametry jak w pliku
+    /// konfiguracyjnym, parametr `output_path` jest opcjonalny i nie jest używany).
+    #[wasm_bindgen(constructor)]
+    pub fn new(config_json: &str) -> Result<WasmSimulation, JsError> {
+        let mut value: serde_json::Value =
+            serde_json::from_str(config_json).map_err(|error| JsError::new(&error.to_string()))?;
+
+        if let Some(object) = value.as_object_mut() {
+            object.entry("output_path").or_insert("".into());
+        }
+
+        let config: Configuration =
+            serde_json::from_value(value).map_err(|error| JsError::new(&error.to_string()))?;
+
+        validate_configuration(&config).map_err(|error| JsError::new(&error.to_string()))?;
+
+        let simulation =
+            Simulation::new(&config).map_err(|error| JsError::new(&error.to_string()))?;
+        let pixels = (config.resolution.0 * config.resolution.1) as usize;
+
+        let mut wasm_simulation = Self {
+            simulation,
+            rgb_buffer: vec![0; pixels * 3],
+            rgba_buffer: vec![255; pixels * 4],
+        };
+
+        wasm_simulation.draw();
+
+        Ok(wasm_simulation)
+    }
+
+    /// Wykonanie kroku symulacji i narysowanie nowej klatki.
+    pub fn step(&mut self) {
+        self.simulation.step();
+        self.draw();
+    }
+
+    /// Szerokość klatki w pikselach.
+    #[wasm_bindgen(getter)]
+    pub fn width(&self) -> u32 {
+        self.simulation.config().resolution.0
+    }
+
+    /// Wysokość klatki w pikselach.
+    #[wasm_bindgen(getter)]
+    pub fn height(&self) -> u32 {
+        self.simulation.config().resolution.1
+    }
+
+    /// Wskaźnik na klatkę w formacie RGBA w pamięci modułu (`width * height * 4` bajtów,
+    /// np. `new Uint8ClampedArray(memory.buffer, simulation.frame(), simulation.frame_len())`).
+    /// Wskaźnik pozostaje ważny do zniszczenia symulacji.
+    pub fn frame(&self) -> *const u8 {
+        self.rgba_buffer.as_ptr()
+    }
+
+    /// Rozmiar klatki w bajtach.
+    pub fn frame_len(&self) -> usize {
+        self.rgba_buffer.len()
+    }
+
+    /// Statystyki symulacji w formacie JSON.
+    pub fn statistics(&self) -> String {
+        let summary = self.simulation.summary();
+
+        let statistics: serde_json::Map<String, serde_json::Value> = SUMMARY_COLUMNS
+            .iter()
+            .zip(summary)
+            .map(|(name, value)| (name.to_string(), value.into()))
+            .collect();
+
+        serde_json::Value::Object(statistics).to_string()
+    }
+}
+
+impl WasmSimulation {
+    /// Narysowanie bieżącego stanu symulacji do bufora RGBA.
+    #[inline]
+    fn draw(&mut self) {
+        self.simulation.draw(&mut self.rgb_buffer);
+
+        for (rgba, rgb) in self
+            .rgba_buffer
+            .chunks_exact_mut(4)
+            .zip(self.rgb_buffer.chunks_exact(3))
+        {
+            rgba[..3].copy_from_slice(rgb);
+        }
+    }
+}
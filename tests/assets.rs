@@ -0,0 +1,137 @@
+//! Testy chronionych obiektów: wyznaczanie komórek obiektów z prostokątów i masek,
+//! rejestracja pierwszego dotarcia ognia i uszkodzonej powierzchni oraz rysowanie obiektów
+//! kolorami dostępnymi w palecie GIF.
+
+#![cfg(feature = "cli")]
+
+mod common;
+
+use common::{directory, valid_configuration};
+
+use forest_fire::{
+    assets::Assets,
+    configuration::{Asset, Configuration},
+    simulation::{initialize_grid_default, Cell, Simulation},
+    visualization::palette,
+};
+
+/// Obiekt złożony z prostokątów (bez maski).
+fn asset(name: &str, color: (u8, u8, u8), rectangles: &[(u32, u32, u32, u32)]) -> Asset {
+    Asset {
+        name: name.to_string(),
+        color,
+        rectangles: rectangles.to_vec(),
+        mask_path: None,
+    }
+}
+
+/// Indeks komórki (x, y) planszy 64 x 64 komórki z obramowaniem.
+fn index(x: usize, y: usize) -> usize {
+    (y + 1) * 66 + x + 1
+}
+
+/// Powierzchnie obiektów (w komórkach).
+fn areas(config: &Configuration) -> Vec<usize> {
+    Assets::new(config)
+        .unwrap()
+        .reports
+        .iter()
+        .map(|report| report.area)
+        .collect()
+}
+
+#[test]
+fn asset_cells_come_from_rectangles_and_masks() {
+    // Plansza 64 x 64 komórki
+    let mut config = valid_configuration();
+
+    // Prostokąty są przycinane do planszy, a wspólne komórki należą do ostatniego obiektu
+    config.assets = vec![
+        asset(
+            "village",
+            (200, 200, 200),
+            &[(0, 0, 4, 4), (60, 62, 10, 10)],
+        ),
+        asset("farm", (200, 150, 0), &[(2, 2, 4, 4)]),
+    ];
+    assert_eq!(areas(&config), [12 + 8, 16]);
+
+    // Maska jest skalowana do rozmiaru planszy: jasna lewa połowa obrazu 2 x 1
+    let mask_path = directory("assets").join("mask.png");
+    image::save_buffer(&mask_path, &[255, 0], 2, 1, image::ColorType::L8).unwrap();
+
+    config.assets = vec![Asset {
+        mask_path: Some(mask_path.to_str().unwrap().to_string()),
+        ..asset("forestry", (0, 0, 255), &[(40, 0, 2, 2)])
+    }];
+    assert_eq!(areas(&config), [32 * 64 + 4]);
+}
+
+#[test]
+fn reports_record_first_impact_and_damaged_area() {
+    let mut config = valid_configuration();
+    config.assets = vec![
+        asset("village", (200, 200, 200), &[(10, 10, 3, 3)]),
+        asset("farm", (200, 150, 0), &[(30, 30, 2, 2)]),
+    ];
+
+    let mut assets = Assets::new(&config).unwrap();
+    let mut state = initialize_grid_default(&config);
+
+    // Ogień poza obiektami nie jest rejestrowany
+    state[index(9, 10)] = Cell::OnFire { progress: 1.0 };
+    assets.update(&state, 1);
+
+    // Ogień dociera do obiektu w kroku 3, a płonąca dalej komórka nie jest liczona ponownie
+    state[index(10, 10)] = Cell::OnFire { progress: 1.0 };
+    assets.update(&state, 3);
+    state[index(11, 10)] = Cell::OnFire { progress: 1.0 };
+    assets.update(&state, 4);
+
+    // Spalona komórka pozostaje uszkodzona
+    state[index(10, 10)] = Cell::Dead;
+    state[index(12, 12)] = Cell::OnFire { progress: 1.0 };
+    assets.update(&state, 7);
+
+    let village = &assets.reports[0];
+    assert_eq!(village.name, "village");
+    assert_eq!(village.area, 9);
+    assert_eq!(village.first_impact_step, Some(3));
+    assert_eq!(village.damaged_area, 3);
+
+    let farm = &assets.reports[1];
+    assert_eq!(farm.first_impact_step, None);
+    assert_eq!(farm.damaged_area, 0);
+}
+
+#[test]
+fn assets_are_drawn_with_palette_colors() {
+    let mut config = valid_configuration();
+    config.seed = Some(1);
+    config.assets = vec![asset("village", (200, 200, 200), &[(0, 0, 8, 8)])];
+
+    let mut simulation = Simulation::new(&config).unwrap();
+    for _ in 0..5 {
+        simulation.step();
+    }
+
+    let mut frame = vec![0; (config.resolution.0 * config.resolution.1 * 3) as usize];
+    simulation.draw(&mut frame);
+
+    // Komórki obiektu (poza płonącymi) mają kolor obiektu niezależnie od stanu komórki
+    let cell_size = config.cell_size as usize;
+    let width = config.resolution.0 as usize;
+
+    for y in 0..8 {
+        for x in 0..8 {
+            if matches!(simulation.state()[index(x, y)], Cell::OnFire { .. }) {
+                continue;
+            }
+
+            let pixel = ((y * cell_size + 1) * width + x * cell_size + 1) * 3;
+            assert_eq!(&frame[pixel..pixel + 3], &[200, 200, 200], "({}, {})", x, y);
+        }
+    }
+
+    assert!(palette(&config, 2, 255).contains(&(200, 200, 200)));
+}
@@ -0,0 +1,82 @@
+//! Testy interfejsu C: `cargo test --features capi`.
+
+#![cfg(feature = "capi")]
+
+mod common;
+
+use common::VALID_CONFIGURATION;
+
+use forest_fire::capi::{
+    forest_fire_counts, forest_fire_create, forest_fire_destroy, forest_fire_frame,
+    forest_fire_last_error, forest_fire_step,
+};
+
+use std::ffi::{CStr, CString};
+
+#[test]
+fn simulation_steps_and_draws_rgb_frames() {
+    let config = CString::new(VALID_CONFIGURATION).unwrap();
+
+    unsafe {
+        let simulation = forest_fire_create(config.as_ptr());
+        assert!(!simulation.is_null());
+
+        forest_fire_step(simulation, 10);
+
+        let counts = forest_fire_counts(simulation);
+        assert_eq!(counts.steps, 10);
+        assert_eq!(
+            counts.alive_cells + counts.snag_cells + counts.on_fire_cells + counts.dead_cells,
+            64 * 64
+        );
+
+        let (mut width, mut height) = (0, 0);
+        let frame = forest_fire_frame(simulation, &mut width, &mut height);
+        assert!(!frame.is_null());
+        assert_eq!((width, height), (256, 256));
+
+        let frame = std::slice::from_raw_parts(frame, 256 * 256 * 3);
+        assert!(frame.iter().any(|&channel| channel != 0));
+
+        forest_fire_destroy(simulation);
+    }
+}
+
+#[test]
+fn invalid_configuration_sets_last_error() {
+    let config = CString::new(VALID_CONFIGURATION.replace("frames: 10", "frames: 0")).unwrap();
+
+    unsafe {
+        assert!(forest_fire_create(config.as_ptr()).is_null());
+
+        let error = CStr::from_ptr(forest_fire_last_error()).to_str().unwrap();
+        assert!(error.contains("frames"), "{}", error);
+
+        assert!(forest_fire_create(std::ptr::null()).is_null());
+        assert!(!forest_fire_last_error().is_null());
+    }
+}
+
+#[test]
+fn null_simulation_is_ignored() {
+    unsafe {
+        forest_fire_step(std::ptr::null_mut(), 1);
+        assert_eq!(forest_fire_counts(std::ptr::null()).steps, 0);
+        assert!(forest_fire_frame(
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut()
+        )
+        .is_null());
+        forest_fire_destroy(std::ptr::null_mut());
+    }
+}
+
+#[test]
+fn committed_header_is_up_to_date() {
+    assert_eq!(
+        include_str!("../include/forest_fire.h"),
+        include_str!(concat!(env!("OUT_DIR"), "/forest_fire.h")),
+        "cbindgen --config cbindgen.toml --output include/forest_fire.h"
+    );
+}
@@ -0,0 +1,112 @@
+//! Testy programu wiersza poleceń: zgodność ze skróconym wywołaniem bez podkomendy,
+//! pomoc w wybranym języku oraz kod wyjścia i wypisywanie błędów na standardowe wyjście
+//! błędów.
+
+#![cfg(feature = "cli")]
+
+mod common;
+
+use common::{directory, VALID_CONFIGURATION};
+
+use std::process::Command;
+
+#[test]
+fn shorthand_accepts_global_options() {
+    let directory = directory("shorthand");
+
+    for (index, global) in [&["--lang", "pl"][..], &["--lang=en"], &[]]
+        .iter()
+        .enumerate()
+    {
+        let output_path = directory.join(format!("{}.gif", index));
+
+        let output = Command::new(env!("CARGO_BIN_EXE_forest_fire"))
+            .args(*global)
+            .arg("config/green_tea.ron")
+            .args(["--set", "frames=1", "--set", "warmup_steps=0", "--output"])
+            .arg(&output_path)
+            .output()
+            .unwrap();
+
+        assert!(
+            output.status.success() && output_path.exists(),
+            "{:?}: {}",
+            global,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
+
+#[test]
+fn help_is_localized() {
+    /// Pomoc podkomendy `run` w języku wybranym na podstawie zmiennych środowiskowych
+    /// i opcji `--lang`.
+    fn help(arguments: &[&str]) -> String {
+        let output = Command::new(env!("CARGO_BIN_EXE_forest_fire"))
+            .args(arguments)
+            .args(["run", "--help"])
+            .env_remove("LC_ALL")
+            .env_remove("LC_MESSAGES")
+            .env("LANG", "C")
+            .output()
+            .unwrap();
+
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    let english = help(&[]);
+    assert!(english.contains("Run the simulation"), "{}", english);
+    assert!(english.contains("--output <PATH>"), "{}", english);
+    assert!(
+        !english.contains(|character| "ąćęłńóśźżĄĆĘŁŃÓŚŹŻ".contains(character)),
+        "{}",
+        english
+    );
+
+    let polish = help(&["--lang", "pl"]);
+    assert!(polish.contains("Przeprowadzenie symulacji"), "{}", polish);
+    assert!(polish.contains("Wypisanie pomocy"), "{}", polish);
+}
+
+#[test]
+fn errors_exit_with_failure_status() {
+    let directory = directory("errors");
+    let invalid_path = directory.join("invalid.ron");
+    std::fs::write(
+        &invalid_path,
+        VALID_CONFIGURATION.replace("frames: 10", "frames: 0"),
+    )
+    .unwrap();
+    let invalid_path = invalid_path.to_str().unwrap();
+
+    for arguments in [
+        &["validate", invalid_path][..],
+        &["run", invalid_path],
+        &[invalid_path],
+        &["validate", "missing.ron"],
+        &["validate", "config/green_tea.ron", "--set", "unknown=1"],
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_forest_fire"))
+            .args(arguments)
+            .args(["--lang", "en"])
+            .output()
+            .unwrap();
+
+        assert_eq!(output.status.code(), Some(1), "{:?}", arguments);
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.to_lowercase().contains("error"),
+            "{:?}: {}",
+            arguments,
+            stderr
+        );
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_forest_fire"))
+        .args(["validate", "config/green_tea.ron"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+}
@@ -0,0 +1,48 @@
+//! Wspólne dane i funkcje pomocnicze testów (każdy plik testów używa tylko części z nich).
+
+#![allow(dead_code)]
+
+use forest_fire::configuration::{parse_configuration, Configuration};
+
+use serde_json::Value;
+
+use std::path::PathBuf;
+
+/// Poprawna konfiguracja w formacie RON (plansza 64 x 64 komórki, 10 klatek).
+pub const VALID_CONFIGURATION: &str = r#"(
+    frames: 10,
+    frame_rate: 25,
+    output_path: "output.gif",
+    resolution: (256, 256),
+    cell_size: 4,
+)"#;
+
+/// Poprawna konfiguracja (plansza 64 x 64 komórki).
+pub fn valid_configuration() -> Configuration {
+    parse_configuration(VALID_CONFIGURATION).unwrap()
+}
+
+/// Mała konfiguracja z ustalonym ziarnem (plansza 32 x 32 komórki, dwie klatki).
+pub fn small_configuration() -> Configuration {
+    let mut config = valid_configuration();
+    config.frames = 2;
+    config.cell_size = 8;
+    config.seed = Some(1);
+
+    config
+}
+
+/// Konfiguracja w postaci wartości JSON (do porównań).
+pub fn as_value(config: &Configuration) -> Value {
+    // Zapis przez tekst zachowuje najkrótszą postać liczb f32
+    serde_json::from_str(&serde_json::to_string(config).unwrap()).unwrap()
+}
+
+/// Katalog tymczasowy na pliki testu.
+pub fn directory(name: &str) -> PathBuf {
+    let directory =
+        std::env::temp_dir().join(format!("forest_fire_{}_{}", name, std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+
+    directory
+}
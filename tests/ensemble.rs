@@ -0,0 +1,141 @@
+//! Testy zespołu symulacji: siatka prawdopodobieństwa spalenia odpowiada mapom spalenia
+//! poszczególnych przebiegów, a plik ze statystykami zbiorczymi zawiera ich średnie
+//! i wariancje.
+
+#![cfg(feature = "cli")]
+
+mod common;
+
+use common::{directory, valid_configuration};
+
+use forest_fire::{
+    configuration::Configuration,
+    ensemble::ensemble,
+    error::ForestFireError,
+    simulation::Simulation,
+    statistics::{Statistics, SUMMARY_COLUMNS},
+};
+
+/// Mała konfiguracja zespołu (plansza 32 x 32 komórki, częste samozapłony).
+fn ensemble_configuration() -> Configuration {
+    let mut config = valid_configuration();
+    config.cell_size = 8;
+    config.frames = 20;
+    config.seed = Some(3);
+    config.self_ignition_probability = 0.0005;
+
+    config
+}
+
+/// Statystyki przebiegu z podanym ziarnem.
+fn run(config: &Configuration, seed: u64) -> Statistics {
+    let mut simulation = Simulation::new(&Configuration {
+        seed: Some(seed),
+        ..config.clone()
+    })
+    .unwrap();
+
+    for _ in 0..config.total_steps() {
+        simulation.step();
+    }
+
+    simulation.statistics()
+}
+
+/// Wiersze pliku CSV (bez nagłówka, jeżeli `header` jest podany, po sprawdzeniu go).
+fn csv_rows(path: &std::path::Path, header: Option<&str>) -> Vec<Vec<String>> {
+    let text = std::fs::read_to_string(path).unwrap();
+    let mut lines = text.lines();
+
+    if let Some(header) = header {
+        assert_eq!(lines.next(), Some(header));
+    }
+
+    lines
+        .map(|line| line.split(',').map(str::to_string).collect())
+        .collect()
+}
+
+#[test]
+fn outputs_match_individual_runs() {
+    let config = ensemble_configuration();
+    let directory = directory("ensemble");
+    let (heatmap, grid, summary) = (
+        directory.join("heatmap.png"),
+        directory.join("grid.csv"),
+        directory.join("summary.csv"),
+    );
+
+    ensemble(
+        &config,
+        3,
+        2,
+        heatmap.to_str().unwrap(),
+        grid.to_str().unwrap(),
+        summary.to_str().unwrap(),
+    )
+    .unwrap();
+
+    // Przebieg n otrzymuje ziarno seed + n
+    let runs: Vec<Statistics> = (3..6).map(|seed| run(&config, seed)).collect();
+    assert!(runs.iter().any(|statistics| statistics.fires() > 0));
+
+    // Siatka zawiera ułamek przebiegów, w których komórka spłonęła
+    let burn_maps: Vec<Vec<bool>> = runs
+        .iter()
+        .map(|statistics| statistics.burn_map(&config))
+        .collect();
+    let grid_rows = csv_rows(&grid, None);
+    assert_eq!(grid_rows.len(), 32);
+
+    for (index, value) in grid_rows.iter().flatten().enumerate() {
+        let count = burn_maps.iter().filter(|map| map[index]).count();
+        assert_eq!(
+            value.parse::<f32>().unwrap(),
+            count as f32 / 3.0,
+            "{}",
+            index
+        );
+    }
+
+    assert_eq!(
+        image::image_dimensions(&heatmap).unwrap(),
+        config.resolution
+    );
+
+    // Średnie i wariancje (nieobciążone) statystyk zbiorczych
+    let summary_rows = csv_rows(&summary, Some("statistic,mean,variance"));
+    assert_eq!(summary_rows.len(), SUMMARY_COLUMNS.len());
+
+    for (column, row) in summary_rows.iter().enumerate() {
+        let values: Vec<f64> = runs
+            .iter()
+            .map(|statistics| statistics.summary()[column])
+            .collect();
+        let mean = values.iter().sum::<f64>() / 3.0;
+        let variance = values
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / 2.0;
+
+        assert_eq!(row[0], SUMMARY_COLUMNS[column]);
+        assert!((row[1].parse::<f64>().unwrap() - mean).abs() <= 1e-9 * mean.abs().max(1.0));
+        assert!(
+            (row[2].parse::<f64>().unwrap() - variance).abs() <= 1e-9 * variance.abs().max(1.0)
+        );
+    }
+}
+
+#[test]
+fn replicas_must_be_positive() {
+    let path = directory("ensemble_empty").join("output");
+    let path = path.to_str().unwrap();
+
+    match ensemble(&ensemble_configuration(), 0, 1, path, path, path) {
+        Err(ForestFireError::Validation { violations }) => {
+            assert_eq!(violations[0].field, "replicas");
+        }
+        result => panic!("{:?}", result),
+    }
+}
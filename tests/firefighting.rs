@@ -0,0 +1,188 @@
+//! Testy straży pożarnej: jednostki kierują się do pożaru zgodnie ze strategią, gaszą
+//! płonące drzewa w zasięgu działania, a zwilżone drzewa nie zapalają się od sąsiadów.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use common::valid_configuration;
+
+use forest_fire::{
+    configuration::{validate_configuration, Configuration, FirefightingStrategy},
+    error::ForestFireError,
+    firefighting::{Firefighter, Firefighters},
+    simulation::{generate_current_state, initialize_grid_default, Cell},
+};
+
+use rand::{rngs::StdRng, SeedableRng};
+
+/// Konfiguracja planszy 10 x 10 komórek z jedną jednostką, bez samozapłonów
+/// i kiełkowania drzew.
+fn firefighting_configuration(strategy: FirefightingStrategy) -> Configuration {
+    let mut config = valid_configuration();
+    config.resolution = (40, 40);
+    config.cell_size = 4;
+    config.seed = Some(1);
+    config.firefighter_count = 1;
+    config.firefighter_strategy = strategy;
+    config.firefighter_speed = 1;
+    config.firefighter_radius = 1;
+    config.self_ignition_probability = 0.0;
+    config.sprout_probability = 0.0;
+    config.random_sprout_probability = 0.0;
+    config.mortality_probability = 0.0;
+    config.burning_rate = 0.01;
+
+    config
+}
+
+/// Indeks komórki (x, y) planszy z obramowaniem.
+fn index(x: usize, y: usize) -> usize {
+    y * 12 + x
+}
+
+/// Jednostka w komórce (x, y) planszy z obramowaniem.
+fn firefighters_at(config: &Configuration, x: usize, y: usize) -> Firefighters {
+    let mut firefighters = Firefighters::new(&mut StdRng::seed_from_u64(1), config);
+    firefighters.units = vec![Firefighter { x, y }];
+
+    firefighters
+}
+
+/// Plansza bez drzew z płonącymi komórkami i żywymi drzewami w podanych miejscach.
+fn board(config: &Configuration, fires: &[(usize, usize)], trees: &[(usize, usize)]) -> Vec<Cell> {
+    let mut state = initialize_grid_default(config);
+
+    for &(x, y) in fires {
+        state[index(x, y)] = Cell::OnFire { progress: 1.0 };
+    }
+
+    for &(x, y) in trees {
+        state[index(x, y)] = Cell::Alive {
+            size: 1.0,
+            color: (0, 255, 0),
+            age: 0,
+        };
+    }
+
+    state
+}
+
+/// Kolejne położenia jednostki (bez zmian planszy między krokami).
+fn positions(
+    config: &Configuration,
+    firefighters: &mut Firefighters,
+    state: &mut [Cell],
+    steps: usize,
+) -> Vec<(usize, usize)> {
+    (0..steps)
+        .map(|_| {
+            let previous_state = state.to_vec();
+            firefighters.update(config, &previous_state, state);
+
+            (firefighters.units[0].x, firefighters.units[0].y)
+        })
+        .collect()
+}
+
+#[test]
+fn unit_moves_toward_fire_and_extinguishes_it() {
+    let config = firefighting_configuration(FirefightingStrategy::Nearest);
+    let mut firefighters = firefighters_at(&config, 1, 1);
+    let mut state = board(&config, &[(8, 5)], &[]);
+
+    let path = positions(&config, &mut firefighters, &mut state, 6);
+    assert_eq!(path, [(2, 2), (3, 3), (4, 4), (5, 5), (6, 5), (7, 5)]);
+
+    // Pożar w zasięgu działania jednostki został ugaszony
+    assert!(matches!(state[index(8, 5)], Cell::Dead));
+    assert_eq!(firefighters.extinguished_cells, 1);
+
+    // Bez pożaru jednostka pozostaje na miejscu
+    assert_eq!(
+        positions(&config, &mut firefighters, &mut state, 2),
+        [(7, 5), (7, 5)]
+    );
+}
+
+#[test]
+fn strategies_choose_targets() {
+    // Najbliższy pożar oraz pożar na froncie (sąsiadujący z żywym lasem)
+    let fires = [(3, 3), (9, 9)];
+    let trees = [(10, 10)];
+
+    let config = firefighting_configuration(FirefightingStrategy::Nearest);
+    let mut state = board(&config, &fires, &trees);
+    let path = positions(&config, &mut firefighters_at(&config, 1, 1), &mut state, 1);
+    assert_eq!(path, [(2, 2)]);
+
+    let config = firefighting_configuration(FirefightingStrategy::Perimeter);
+    let mut state = board(&config, &fires, &trees);
+    let path = positions(&config, &mut firefighters_at(&config, 1, 1), &mut state, 1);
+    assert_eq!(path, [(2, 2)]);
+    let path = positions(&config, &mut firefighters_at(&config, 5, 1), &mut state, 1);
+    assert_eq!(path, [(6, 2)]);
+
+    // Obrona obszaru: pożar najbliższy chronionemu punktowi, a bez pożaru powrót do niego
+    let config = firefighting_configuration(FirefightingStrategy::ProtectArea { x: 9, y: 8 });
+    let mut state = board(&config, &fires, &trees);
+    let path = positions(&config, &mut firefighters_at(&config, 3, 4), &mut state, 1);
+    assert_eq!(path, [(4, 5)]);
+
+    let mut state = board(&config, &[], &trees);
+    let path = positions(&config, &mut firefighters_at(&config, 8, 10), &mut state, 2);
+    assert_eq!(path, [(9, 9), (10, 9)]);
+}
+
+#[test]
+fn wetted_trees_do_not_ignite() {
+    let mut config = firefighting_configuration(FirefightingStrategy::ProtectArea { x: 4, y: 4 });
+    config.inflammability = 1.0;
+
+    for (wetting_steps, protected) in [(10, true), (0, false)] {
+        config.firefighter_wetting_steps = wetting_steps;
+
+        // Jednostka broniąca komórki (5, 5) zwilża drzewo, zanim pojawi się pożar
+        let mut firefighters = firefighters_at(&config, 5, 5);
+        let mut state = board(&config, &[], &[(5, 5)]);
+        positions(&config, &mut firefighters, &mut state, 1);
+
+        // Pożar poza zasięgiem działania jednostki przenosi się na niezwilżone drzewo
+        state[index(6, 6)] = Cell::OnFire { progress: 1.0 };
+        let mut next_state = initialize_grid_default(&config);
+        generate_current_state(
+            &mut StdRng::seed_from_u64(1),
+            &config,
+            &state,
+            &mut next_state,
+        );
+        assert!(matches!(next_state[index(5, 5)], Cell::OnFire { .. }));
+
+        firefighters.update(&config, &state, &mut next_state);
+        assert_eq!(
+            matches!(next_state[index(5, 5)], Cell::Alive { .. }),
+            protected,
+            "{}",
+            wetting_steps
+        );
+    }
+}
+
+#[test]
+fn protected_point_must_lie_on_board() {
+    // Plansza 64 x 64 komórki
+    let mut config = valid_configuration();
+    config.firefighter_count = 1;
+    config.firefighter_strategy = FirefightingStrategy::ProtectArea { x: 3, y: 64 };
+
+    match validate_configuration(&config) {
+        Err(ForestFireError::Validation { violations }) => {
+            assert_eq!(violations.len(), 1, "{:?}", violations);
+            assert_eq!(violations[0].field, "firefighter_strategy");
+        }
+        result => panic!("{:?}", result),
+    }
+
+    config.firefighter_strategy = FirefightingStrategy::ProtectArea { x: 3, y: 63 };
+    assert!(validate_configuration(&config).is_ok());
+}
@@ -0,0 +1,166 @@
+//! Testy formatów plików konfiguracyjnych: konfiguracja zapisana w każdym z formatów jest
+//! odczytywana bez zmian, a pominięte parametry przyjmują te same wartości domyślne.
+//! Konfiguracja efektywna zapisana obok pliku wynikowego pozwala powtórzyć symulację.
+
+#![cfg(feature = "cli")]
+
+mod common;
+
+use common::{as_value, valid_configuration};
+
+use forest_fire::configuration::{
+    effective_configuration_path, load_configuration, override_configuration,
+    parse_configuration_as, save_effective_configuration, serialize_configuration, Asset,
+    ConfigurationFormat, FirefightingStrategy, OutputFormat, VERSION,
+};
+
+const FORMATS: [ConfigurationFormat; 4] = [
+    ConfigurationFormat::Ron,
+    ConfigurationFormat::Toml,
+    ConfigurationFormat::Json,
+    ConfigurationFormat::Yaml,
+];
+
+#[test]
+fn format_is_selected_by_extension() {
+    for (path, format) in [
+        ("config.ron", ConfigurationFormat::Ron),
+        ("config.toml", ConfigurationFormat::Toml),
+        ("config.json", ConfigurationFormat::Json),
+        ("config.yaml", ConfigurationFormat::Yaml),
+        ("config.YML", ConfigurationFormat::Yaml),
+        ("config", ConfigurationFormat::Ron),
+    ] {
+        assert_eq!(ConfigurationFormat::from_path(path), format, "{}", path);
+    }
+}
+
+#[test]
+fn configuration_survives_conversion() {
+    let mut config = load_configuration("config/green_tea.ron").unwrap();
+    config.seed = Some(42);
+    config.firefighter_strategy = FirefightingStrategy::ProtectArea { x: 10, y: 20 };
+    config.assets = vec![Asset {
+        name: "village".to_string(),
+        color: (255, 0, 0),
+        rectangles: vec![(1, 2, 3, 4)],
+        mask_path: None,
+    }];
+
+    for format in FORMATS {
+        let text = serialize_configuration(&config, format).unwrap();
+        let parsed = parse_configuration_as(&text, format).unwrap();
+
+        assert_eq!(
+            as_value(&parsed),
+            as_value(&config),
+            "{:?}:\n{}",
+            format,
+            text
+        );
+    }
+}
+
+#[test]
+fn defaults_do_not_depend_on_format() {
+    let sources = [
+        r#"(frames: 10, frame_rate: 25, output_path: "a.gif", resolution: (256, 256),
+            cell_size: 4)"#,
+        "frames = 10\nframe_rate = 25\noutput_path = \"a.gif\"\nresolution = [256, 256]\n\
+        cell_size = 4\n",
+        r#"{"frames": 10, "frame_rate": 25, "output_path": "a.gif", "resolution": [256, 256],
+            "cell_size": 4}"#,
+        "frames: 10\nframe_rate: 25\noutput_path: a.gif\nresolution: [256, 256]\ncell_size: 4\n",
+    ];
+
+    let configs: Vec<serde_json::Value> = FORMATS
+        .iter()
+        .zip(sources)
+        .map(|(&format, text)| as_value(&parse_configuration_as(text, format).unwrap()))
+        .collect();
+
+    assert!(configs.iter().all(|config| *config == configs[0]));
+}
+
+#[test]
+fn parse_errors_report_position() {
+    for (format, text, line) in [
+        (
+            ConfigurationFormat::Toml,
+            "frames = 10\nframe_rate = \"x\"\n",
+            2,
+        ),
+        (
+            ConfigurationFormat::Json,
+            "{\"frames\": 10,\n\"frame_rate\": x}",
+            2,
+        ),
+        (ConfigurationFormat::Yaml, "frames: 10\nframe_rate: [\n", 2),
+    ] {
+        match parse_configuration_as(text, format) {
+            Err(forest_fire::error::ForestFireError::Parse { line: parsed, .. }) => {
+                assert_eq!(parsed, Some(line), "{:?}", format)
+            }
+            result => panic!("{:?}: {:?}", format, result.map(|_| ())),
+        }
+    }
+}
+
+#[test]
+fn overrides_set_parameters_without_value() {
+    let config = valid_configuration();
+    assert_eq!((config.version.as_deref(), config.seed), (None, None));
+
+    let overridden = override_configuration(
+        &config,
+        &[
+            "version=0.1.0".to_string(),
+            "seed=5".to_string(),
+            "output_format=Apng".to_string(),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(overridden.version.as_deref(), Some("0.1.0"));
+    assert_eq!(overridden.seed, Some(5));
+    assert_eq!(overridden.output_format, Some(OutputFormat::Apng));
+
+    assert!(override_configuration(&config, &["speed=5".to_string()]).is_err());
+}
+
+#[test]
+fn effective_configuration_path_is_next_to_output() {
+    for (output, path) in [
+        ("green_tea.gif", "green_tea.gif.config.ron"),
+        ("out/sweep.csv", "out/sweep.csv.config.ron"),
+        ("frames/", "frames.config.ron"),
+    ] {
+        assert_eq!(effective_configuration_path(output), path, "{}", output);
+    }
+}
+
+#[test]
+fn effective_configuration_reproduces_the_run() {
+    let mut config = load_configuration("config/green_tea.ron").unwrap();
+    config.seed = Some(i64::MAX as u64);
+
+    let directory =
+        std::env::temp_dir().join(format!("forest_fire_effective_{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+
+    for format in FORMATS {
+        let path = directory.join(format!("effective.{:?}", format).to_lowercase());
+        let path = path.to_str().unwrap();
+
+        save_effective_configuration(&config, path).unwrap();
+        let loaded = load_configuration(path).unwrap();
+
+        assert_eq!(loaded.version.as_deref(), Some(VERSION), "{:?}", format);
+        assert_eq!(
+            as_value(&loaded),
+            as_value(&config.effective()),
+            "{:?}",
+            format
+        );
+    }
+}
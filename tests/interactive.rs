@@ -0,0 +1,125 @@
+//! Testy powtórki sesji interaktywnej: sesja zapisana do dziennika jest powtarzana bez
+//! terminala z identycznymi statystykami, łącznie ze zdarzeniami po ostatnim kroku.
+
+#![cfg(feature = "cli")]
+
+mod common;
+
+use common::{directory, valid_configuration};
+
+use forest_fire::{
+    configuration::Configuration,
+    interactive::{
+        load_session_log, record_event, replay, save_session_log, SessionEvent, SessionLog,
+    },
+    simulation::{Cell, Simulation},
+    statistics::Statistics,
+};
+
+use std::process::Command;
+
+/// Konfiguracja sesji (z ziarnem, 40 kroków symulacji).
+fn session_configuration() -> Configuration {
+    let mut config = valid_configuration();
+    config.frames = 40;
+    config.seed = Some(7);
+
+    config.effective()
+}
+
+/// Współrzędne pierwszej komórki z żywym drzewem.
+fn alive_cell(simulation: &Simulation) -> (u32, u32) {
+    let config = simulation.config();
+    let cells_x = config.resolution.0 / config.cell_size;
+    let cells_y = config.resolution.1 / config.cell_size;
+
+    (0..cells_y)
+        .flat_map(|y| (0..cells_x).map(move |x| (x, y)))
+        .find(|&(x, y)| {
+            matches!(
+                simulation.state()[((y + 1) * (cells_x + 2) + x + 1) as usize],
+                Cell::Alive { .. }
+            )
+        })
+        .unwrap()
+}
+
+/// Sesja z interwencjami w trakcie symulacji i po jej ostatnim kroku.
+fn record_session(config: &Configuration) -> (Vec<(u32, SessionEvent)>, Statistics) {
+    let mut simulation = Simulation::new(config).unwrap();
+    let mut events = Vec::new();
+
+    for step in 0..config.total_steps() {
+        if step == 5 {
+            let (x, y) = alive_cell(&simulation);
+            record_event(&mut simulation, &mut events, SessionEvent::Ignite { x, y });
+            record_event(
+                &mut simulation,
+                &mut events,
+                SessionEvent::SetInflammability(0.5),
+            );
+        }
+
+        if step == 20 {
+            record_event(
+                &mut simulation,
+                &mut events,
+                SessionEvent::Clear { x: 3, y: 4 },
+            );
+            record_event(
+                &mut simulation,
+                &mut events,
+                SessionEvent::SetGrowthRate(0.01),
+            );
+        }
+
+        simulation.step();
+    }
+
+    let (x, y) = alive_cell(&simulation);
+    record_event(&mut simulation, &mut events, SessionEvent::Ignite { x, y });
+
+    (events, simulation.statistics())
+}
+
+#[test]
+fn replay_reproduces_recorded_session() {
+    let config = session_configuration();
+    let (events, recorded) = record_session(&config);
+
+    assert_eq!(events.last().unwrap().0, config.total_steps());
+
+    let log_path = directory("replay").join("session.ron");
+    let log_path = log_path.to_str().unwrap();
+    save_session_log(log_path, &SessionLog { config, events }).unwrap();
+
+    let log = load_session_log(log_path).unwrap();
+    assert_eq!(replay(&log.config, &log.events).unwrap(), recorded);
+
+    // Zdarzenie po ostatnim kroku zmienia wynik, więc musi zostać powtórzone
+    let without_last = &log.events[..log.events.len() - 1];
+    assert_ne!(replay(&log.config, without_last).unwrap(), recorded);
+}
+
+#[test]
+fn replay_runs_without_terminal() {
+    let config = session_configuration();
+    let (events, recorded) = record_session(&config);
+
+    let log_path = directory("replay_cli").join("session.ron");
+    save_session_log(log_path.to_str().unwrap(), &SessionLog { config, events }).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_forest_fire"))
+        .args(["--lang", "en", "replay"])
+        .arg(&log_path)
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(
+        stdout.contains(&format!("Number of ignitions: {}", recorded.ignitions)),
+        "{}",
+        stdout
+    );
+}
@@ -0,0 +1,44 @@
+//! Testy wyboru języka komunikatów. Język jest ustawiany dla całego procesu, dlatego
+//! wszystkie sprawdzenia zmieniające język znajdują się w jednym teście.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use common::valid_configuration;
+
+use forest_fire::{
+    configuration::validate_configuration,
+    locale::{language, set_language, Language},
+};
+
+#[test]
+fn language_codes_are_recognised() {
+    assert_eq!(Language::from_code("en"), Some(Language::English));
+    assert_eq!(Language::from_code("en_GB.UTF-8"), Some(Language::English));
+    assert_eq!(Language::from_code("C"), Some(Language::English));
+    assert_eq!(Language::from_code("pl"), Some(Language::Polish));
+    assert_eq!(Language::from_code("pl_PL.UTF-8"), Some(Language::Polish));
+    assert_eq!(Language::from_code("de_DE.UTF-8"), None);
+}
+
+#[test]
+fn messages_follow_selected_language() {
+    let mut config = valid_configuration();
+    config.frames = 0;
+
+    assert_eq!(language(), Language::English);
+    assert_eq!(
+        validate_configuration(&config).unwrap_err().to_string(),
+        "Configuration error: parameter \"frames\" must be greater than 0, got: 0"
+    );
+
+    set_language(Language::Polish);
+    assert_eq!(
+        validate_configuration(&config).unwrap_err().to_string(),
+        "Błąd konfiguracji: parametr \"frames\" musi przyjmować wartości większe od 0, \
+        podano: 0"
+    );
+
+    set_language(Language::English);
+}
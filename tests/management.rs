@@ -0,0 +1,275 @@
+//! Testy zarządzania lasem: strategie wyboru fragmentów lasu, ograniczenie zabiegów do
+//! obszarów zarządzanych, wypalanie kontrolowane rozprzestrzeniające się jak pożar
+//! oraz porównanie z symulacją bez zarządzania.
+
+#![cfg(feature = "cli")]
+
+mod common;
+
+use common::valid_configuration;
+
+use forest_fire::{
+    configuration::{Configuration, ManagementAction, ManagementStrategy},
+    management::Management,
+    simulation::{generate_current_state, simulate_unmanaged, Cell, Simulation},
+    statistics::Statistics,
+};
+
+use rand::{rngs::StdRng, SeedableRng};
+
+/// Konfiguracja planszy 8 x 8 komórek podzielonej na cztery bloki 4 x 4 poddawane
+/// zabiegom w każdym kroku symulacji.
+fn management_configuration(
+    action: ManagementAction,
+    strategy: ManagementStrategy,
+    fraction: f32,
+) -> Configuration {
+    let mut config = valid_configuration();
+    config.resolution = (32, 32);
+    config.cell_size = 4;
+    config.seed = Some(1);
+    config.management_interval = 1;
+    config.management_action = action;
+    config.management_strategy = strategy;
+    config.management_fraction = fraction;
+    config.management_patch_size = 4;
+
+    config
+}
+
+/// Plansza (z obramowaniem) wypełniona drzewami o podanym rozmiarze i wieku zależnym
+/// od położenia komórki.
+fn forest(config: &Configuration, size: f32, age: impl Fn(u32, u32) -> u32) -> Vec<Cell> {
+    let cells_x = config.resolution.0 / config.cell_size + 2;
+    let cells_y = config.resolution.1 / config.cell_size + 2;
+
+    (0..cells_y)
+        .flat_map(|y| (0..cells_x).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            if x == 0 || y == 0 || x == cells_x - 1 || y == cells_y - 1 {
+                Cell::Dead
+            } else {
+                Cell::Alive {
+                    size,
+                    color: (0, 255, 0),
+                    age: age(x - 1, y - 1),
+                }
+            }
+        })
+        .collect()
+}
+
+/// Numery bloków 4 x 4 (wierszami), w których usunięto wszystkie drzewa.
+fn cleared_blocks(config: &Configuration, state: &[Cell]) -> Vec<u32> {
+    let cells_x = config.resolution.0 / config.cell_size;
+
+    (0..4)
+        .filter(|block| {
+            (0..16).all(|cell| {
+                let x = block % 2 * 4 + cell % 4;
+                let y = block / 2 * 4 + cell / 4;
+
+                matches!(
+                    state[((y + 1) * (cells_x + 2) + x + 1) as usize],
+                    Cell::Dead
+                )
+            })
+        })
+        .collect()
+}
+
+/// Bloki wycięte w kolejnych zabiegach (każdy zabieg na nowej planszy).
+fn treated_blocks(config: &Configuration, treatments: usize) -> Vec<Vec<u32>> {
+    let mut management = Management::new(config);
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut statistics = Statistics::new(config);
+
+    (0..treatments)
+        .map(|_| {
+            let mut state = forest(config, 1.0, |x, y| x + y);
+            management.update(&mut rng, config, &mut state, &mut statistics);
+
+            cleared_blocks(config, &state)
+        })
+        .collect()
+}
+
+#[test]
+fn grid_pattern_treats_blocks_cyclically() {
+    let config = management_configuration(
+        ManagementAction::ClearCut,
+        ManagementStrategy::GridPattern,
+        0.25,
+    );
+
+    assert_eq!(
+        treated_blocks(&config, 5),
+        [vec![0], vec![1], vec![2], vec![3], vec![0]]
+    );
+}
+
+#[test]
+fn oldest_first_treats_oldest_blocks() {
+    let config = management_configuration(
+        ManagementAction::ClearCut,
+        ManagementStrategy::OldestFirst,
+        0.5,
+    );
+
+    // Najstarsze drzewa rosną w dolnym prawym, a następnie w dolnym lewym bloku
+    let mut management = Management::new(&config);
+    let mut state = forest(&config, 1.0, |x, y| y * 10 + x);
+    management.update(
+        &mut StdRng::seed_from_u64(1),
+        &config,
+        &mut state,
+        &mut Statistics::new(&config),
+    );
+
+    assert_eq!(cleared_blocks(&config, &state), [2, 3]);
+    assert_eq!(management.treated_cells, 32);
+}
+
+#[test]
+fn treatments_are_limited_to_managed_regions() {
+    let mut config = management_configuration(
+        ManagementAction::ClearCut,
+        ManagementStrategy::OldestFirst,
+        0.5,
+    );
+
+    // Jedyny blok z obszarem zarządzanym jest poddawany zabiegowi tylko w tym obszarze
+    config.management_regions = vec![(0, 0, 4, 2)];
+    let mut management = Management::new(&config);
+    let mut state = forest(&config, 1.0, |x, y| y * 10 + x);
+    management.update(
+        &mut StdRng::seed_from_u64(1),
+        &config,
+        &mut state,
+        &mut Statistics::new(&config),
+    );
+
+    let treated: Vec<bool> = state
+        .iter()
+        .map(|cell| matches!(cell, Cell::Dead))
+        .collect();
+    let expected = forest(&config, 1.0, |x, y| y * 10 + x)
+        .iter()
+        .enumerate()
+        .map(|(index, cell)| {
+            let (x, y) = (index % 10, index / 10);
+            matches!(cell, Cell::Dead) || ((1..=4).contains(&x) && (1..=2).contains(&y))
+        })
+        .collect::<Vec<bool>>();
+    assert_eq!(treated, expected);
+    assert_eq!(management.treated_cells, 8);
+}
+
+#[test]
+fn random_patches_treat_whole_blocks() {
+    let config = management_configuration(
+        ManagementAction::ClearCut,
+        ManagementStrategy::RandomPatches,
+        0.5,
+    );
+
+    let treatments = treated_blocks(&config, 20);
+    assert!(treatments.iter().all(|blocks| blocks.len() == 2));
+
+    // Wybór bloków jest losowy, ale powtarzalny dla tego samego ziarna
+    assert!(treatments.iter().any(|blocks| *blocks != treatments[0]));
+    assert_eq!(treated_blocks(&config, 20), treatments);
+}
+
+#[test]
+fn controlled_burn_ignites_snags_and_young_trees() {
+    let mut config = management_configuration(
+        ManagementAction::ControlledBurn,
+        ManagementStrategy::GridPattern,
+        0.25,
+    );
+    config.inflammability = 1.0;
+    config.self_ignition_probability = 0.0;
+    config.burning_rate = 0.5;
+
+    // Pierwszy blok: młode drzewa w lewej kolumnie, posusz w drugiej kolumnie, a dalej
+    // i w pozostałych blokach duże drzewa
+    let mut state = forest(&config, 1.0, |_, _| 0);
+    for y in 1..=4 {
+        state[y * 10 + 1] = Cell::Alive {
+            size: 0.3,
+            color: (0, 255, 0),
+            age: 0,
+        };
+        state[y * 10 + 2] = Cell::Snag { integrity: 1.0 };
+    }
+
+    let mut management = Management::new(&config);
+    let mut statistics = Statistics::new(&config);
+    let mut rng = StdRng::seed_from_u64(1);
+    management.update(&mut rng, &config, &mut state, &mut statistics);
+
+    let on_fire: Vec<usize> = (0..state.len())
+        .filter(|&index| matches!(state[index], Cell::OnFire { .. }))
+        .collect();
+    assert_eq!(on_fire, [11, 12, 21, 22, 31, 32, 41, 42]);
+    assert_eq!(management.treated_cells, 8);
+
+    // Wypalany blok jest jednym pożarem
+    assert_eq!(statistics.fires(), 1);
+    assert_eq!(statistics.ignitions, 8);
+
+    // Ogień przenosi się na duże drzewa sąsiadujące z wypalanym blokiem, a nowe zapłony
+    // należą do tego samego pożaru
+    let mut next_state = forest(&config, 1.0, |_, _| 0);
+    generate_current_state(&mut rng, &config, &state, &mut next_state);
+    statistics.update(&config, &state, &next_state);
+
+    assert!((1..=5).all(|y| matches!(next_state[y * 10 + 3], Cell::OnFire { .. })));
+    assert_eq!(statistics.fires(), 1);
+    assert_eq!(statistics.max_fire_size(), 15);
+}
+
+#[test]
+fn management_reduces_fire_size() {
+    let mut config = management_configuration(
+        ManagementAction::ClearCut,
+        ManagementStrategy::GridPattern,
+        0.25,
+    );
+    config.resolution = (256, 256);
+    config.frames = 100;
+    config.management_interval = 5;
+    config.management_patch_size = 8;
+    config.inflammability = 0.8;
+    config.self_ignition_probability = 0.0001;
+    let config = config.effective();
+
+    let mut simulation = Simulation::new(&config).unwrap();
+    for _ in 0..config.total_steps() {
+        simulation.step();
+    }
+    let managed = simulation.statistics();
+
+    // Symulacja referencyjna pomija zabiegi, ale przebiega tak samo jak symulacja
+    // z wyłączonym zarządzaniem
+    let unmanaged = simulate_unmanaged(&config).unwrap();
+    let mut simulation = Simulation::new(&Configuration {
+        management_interval: 0,
+        ..config.clone()
+    })
+    .unwrap();
+    for _ in 0..config.total_steps() {
+        simulation.step();
+    }
+    assert_eq!(simulation.statistics(), unmanaged);
+
+    assert!(managed.treated_cells > 0);
+    assert_eq!(unmanaged.treated_cells, 0);
+    assert!(
+        managed.max_fire_size() < unmanaged.max_fire_size(),
+        "{} / {}",
+        managed.max_fire_size(),
+        unmanaged.max_fire_size()
+    );
+}
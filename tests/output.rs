@@ -0,0 +1,421 @@
+//! Testy zapisu animacji: opóźnienia klatek GIF wynikają z liczby klatek na sekundę
+//! i nie są krótsze niż 2/100 s, paleta GIF zawsze zawiera kolory ognia i posuszu,
+//! a pozostałe kodery zapisują pliki o poprawnej strukturze (rozszerzenie .png oznacza
+//! animację APNG) lub przekazują klatki zewnętrznemu koderowi i zgłaszają jego błędy.
+
+#![cfg(feature = "cli")]
+
+mod common;
+
+use common::{directory, small_configuration, valid_configuration};
+
+use forest_fire::{
+    configuration::{validate_configuration, Configuration, OutputFormat, GIF_MAX_FRAME_RATE},
+    error::{ForestFireError, Operation},
+    output::create_encoder,
+    simulation::simulate,
+    visualization::palette,
+};
+
+use image::{codecs::webp::WebPDecoder, AnimationDecoder};
+
+use std::fs::File;
+
+/// Opóźnienia (w setnych częściach sekundy) kolejnych klatek animacji GIF zapisanej
+/// z podaną liczbą klatek na sekundę.
+fn gif_delays(frame_rate: u32, frames: u32) -> Vec<u16> {
+    let path = directory("delays").join(format!("{}.gif", frame_rate));
+
+    let mut config = small_configuration();
+    config.frames = frames;
+    config.frame_rate = frame_rate;
+    config.output_path = path.to_str().unwrap().to_string();
+
+    validate_configuration(&config).unwrap();
+    simulate(&config).unwrap();
+
+    let mut decoder = gif::DecodeOptions::new()
+        .read_info(File::open(&path).unwrap())
+        .unwrap();
+    let mut delays = Vec::new();
+
+    while let Some(frame) = decoder.read_next_frame().unwrap() {
+        delays.push(frame.delay);
+    }
+
+    delays
+}
+
+#[test]
+fn gif_delays_follow_frame_rate() {
+    assert_eq!(gif_delays(GIF_MAX_FRAME_RATE, 10), vec![2; 10]);
+    assert_eq!(gif_delays(25, 10), vec![4; 10]);
+
+    // Opóźnienia są zaokrąglane bez kumulacji błędu: 40 klatek trwa dokładnie sekundę
+    let delays = gif_delays(40, 40);
+    assert!(
+        delays.iter().all(|delay| (2..=3).contains(delay)),
+        "{:?}",
+        delays
+    );
+    assert_eq!(
+        delays.iter().map(|&delay| u32::from(delay)).sum::<u32>(),
+        100
+    );
+}
+
+#[test]
+fn long_forest_palette_keeps_fire_and_snag_colors() {
+    let mut config = valid_configuration();
+
+    // Paleta lasu mieszcząca się w palecie GIF w jednym odcieniu oraz paleta zbyt długa
+    for length in [200, 300] {
+        config.forest_color_palette = (0..length)
+            .map(|index| (0, (index % 256) as u8, (index / 256) as u8))
+            .collect();
+
+        for levels in [2, 8] {
+            let colors = palette(&config, levels, 255);
+
+            assert!(colors.len() <= 255, "{}", colors.len());
+            assert!(colors.contains(&config.fire_color));
+            assert!(colors.contains(&config.snag_color));
+            assert!(colors.contains(&config.ground_color));
+            // Kolory lasu wypełniają pozostałą część palety
+            assert!(colors.contains(&config.forest_color_palette[0]));
+            assert!(colors.len() > 200, "{}", colors.len());
+        }
+    }
+}
+
+/// Konfiguracja kodera zapisującego animację o podanej rozdzielczości i liczbie klatek
+/// do pliku o podanej nazwie w katalogu tymczasowym testu.
+fn encoder_configuration(
+    name: &str,
+    file_name: &str,
+    resolution: (u32, u32),
+    frames: u32,
+) -> Configuration {
+    let mut config = valid_configuration();
+    config.frames = frames;
+    config.output_path = directory(name)
+        .join(file_name)
+        .to_str()
+        .unwrap()
+        .to_string();
+    config.resolution = resolution;
+    config.cell_size = 1;
+
+    config
+}
+
+/// Klatki RGB animacji z różnymi kolorami pikseli (każda klatka jest inna).
+fn test_frames(config: &Configuration) -> Vec<Vec<u8>> {
+    (0..config.frames)
+        .map(|frame| {
+            (0..config.resolution.0 * config.resolution.1 * 3)
+                .map(|index| (index * 7 + frame * 40) as u8)
+                .collect()
+        })
+        .collect()
+}
+
+/// Zapis klatek koderem odpowiadającym konfiguracji.
+fn encode(config: &Configuration, frames: &[Vec<u8>]) {
+    let mut encoder = create_encoder(config).unwrap();
+
+    for frame in frames {
+        encoder.encode_frame(frame).unwrap();
+    }
+
+    encoder.finish().unwrap();
+}
+
+/// Fragmenty pliku RIFF (nazwa i zawartość) wraz ze sprawdzeniem ich rozmiarów
+/// i wyrównania do parzystej liczby bajtów.
+fn riff_chunks(data: &[u8]) -> Vec<(&[u8], &[u8])> {
+    let mut chunks = Vec::new();
+    let mut offset = 0;
+
+    while offset < data.len() {
+        assert!(
+            offset + 8 <= data.len(),
+            "truncated chunk header at {}",
+            offset
+        );
+
+        let name = &data[offset..offset + 4];
+        let size = u32::from_le_bytes(data[offset + 4..offset + 8].try_into().unwrap()) as usize;
+        let padded_size = size + size % 2;
+        assert!(
+            offset + 8 + padded_size <= data.len(),
+            "chunk {:?} exceeds its parent",
+            String::from_utf8_lossy(name)
+        );
+
+        chunks.push((name, &data[offset + 8..offset + 8 + size]));
+        offset += 8 + padded_size;
+    }
+
+    chunks
+}
+
+/// Liczba 24-bitowa (little-endian).
+fn u24(data: &[u8]) -> u32 {
+    u32::from_le_bytes([data[0], data[1], data[2], 0])
+}
+
+#[test]
+fn webp_frames_decode_with_image() {
+    for resolution in [(8, 6), (5, 3), (7, 1)] {
+        let config = encoder_configuration("webp_decode", "output.webp", resolution, 3);
+        let frames = test_frames(&config);
+        encode(&config, &frames);
+
+        let decoder = WebPDecoder::new(File::open(&config.output_path).unwrap()).unwrap();
+        let decoded = decoder.into_frames().collect_frames().unwrap();
+
+        assert_eq!(decoded.len(), frames.len());
+
+        // Klatki są kodowane bezstratnie
+        for (decoded, frame) in decoded.iter().zip(&frames) {
+            let buffer = decoded.buffer();
+            assert_eq!(buffer.dimensions(), resolution);
+
+            let rgb: Vec<u8> = buffer
+                .pixels()
+                .flat_map(|pixel| [pixel[0], pixel[1], pixel[2]])
+                .collect();
+            assert_eq!(&rgb, frame);
+
+            let (numerator, denominator) = decoded.delay().numer_denom_ms();
+            assert_eq!(numerator / denominator, 1000 / config.frame_rate);
+        }
+    }
+}
+
+#[test]
+fn webp_chunks_are_sized_and_padded() {
+    for resolution in [(5, 3), (9, 7), (16, 16)] {
+        let config = encoder_configuration("webp_chunks", "output.webp", resolution, 4);
+        let frames = test_frames(&config);
+        encode(&config, &frames);
+
+        let data = std::fs::read(&config.output_path).unwrap();
+
+        // Rozmiar w nagłówku RIFF obejmuje cały plik bez pierwszych 8 bajtów
+        let riff = riff_chunks(&data);
+        assert_eq!(riff.len(), 1);
+        assert_eq!(riff[0].0, b"RIFF");
+        assert_eq!(riff[0].1.len(), data.len() - 8);
+        assert_eq!(&riff[0].1[..4], b"WEBP");
+
+        let chunks = riff_chunks(&riff[0].1[4..]);
+        let names: Vec<&[u8]> = chunks.iter().map(|(name, _)| *name).collect();
+        let mut expected: Vec<&[u8]> = vec![b"VP8X", b"ANIM"];
+        expected.extend(std::iter::repeat_n(&b"ANMF"[..], frames.len()));
+        assert_eq!(names, expected);
+
+        // Rozmiar płótna zapisany jako (szerokość - 1, wysokość - 1) z flagą animacji
+        let vp8x = chunks[0].1;
+        assert_eq!(vp8x.len(), 10);
+        assert_eq!(vp8x[0] & 0x02, 0x02);
+        assert_eq!((u24(&vp8x[4..]) + 1, u24(&vp8x[7..]) + 1), resolution);
+
+        for (_, anmf) in &chunks[2..] {
+            // Położenie, rozmiar i czas trwania klatki
+            assert_eq!((u24(&anmf[0..]), u24(&anmf[3..])), (0, 0));
+            assert_eq!((u24(&anmf[6..]) + 1, u24(&anmf[9..]) + 1), resolution);
+            assert_eq!(u24(&anmf[12..]), 1000 / config.frame_rate);
+
+            // Dane klatki to dokładnie jeden fragment VP8L wypełniający fragment ANMF
+            let frame_chunks = riff_chunks(&anmf[16..]);
+            assert_eq!(frame_chunks.len(), 1);
+            assert_eq!(frame_chunks[0].0, b"VP8L");
+        }
+    }
+}
+
+#[test]
+fn png_extension_writes_animated_png() {
+    for file_name in ["output.png", "output.APNG"] {
+        let config = encoder_configuration("apng_extension", file_name, (4, 4), 1);
+        assert_eq!(config.resolved_output_format(), Some(OutputFormat::Apng));
+    }
+
+    for frame_rate in [25, 7] {
+        let mut config = encoder_configuration("apng", "output.png", (5, 3), 3);
+        config.frame_rate = frame_rate;
+        let frames = test_frames(&config);
+        encode(&config, &frames);
+
+        let decoder = png::Decoder::new(File::open(&config.output_path).unwrap());
+        let mut reader = decoder.read_info().unwrap();
+
+        // Liczba klatek animacji (fragment acTL) i nieskończone zapętlenie
+        let animation = reader.info().animation_control().copied().unwrap();
+        assert_eq!(animation.num_frames, frames.len() as u32);
+        assert_eq!(animation.num_plays, 0);
+
+        let mut buffer = vec![0; reader.output_buffer_size()];
+
+        for frame in &frames {
+            let info = reader.next_frame(&mut buffer).unwrap();
+            assert_eq!((info.width, info.height), config.resolution);
+            assert_eq!(&buffer[..info.buffer_size()], frame.as_slice());
+
+            // Czas trwania klatki (fragment fcTL) równy 1/frame_rate s
+            let control = reader.info().frame_control().copied().unwrap();
+            assert_eq!(
+                (control.delay_num, control.delay_den),
+                (1, frame_rate as u16)
+            );
+        }
+    }
+}
+
+#[test]
+fn y4m_frames_follow_header() {
+    let config = encoder_configuration("y4m", "output.y4m", (5, 3), 2);
+    let pixels = 5 * 3;
+
+    // Klatka czarna i biała (zakres ograniczony: Y 16-235, U i V 128)
+    let frames = vec![vec![0; pixels * 3], vec![255; pixels * 3]];
+    encode(&config, &frames);
+
+    let data = std::fs::read(&config.output_path).unwrap();
+    let header = b"YUV4MPEG2 W5 H3 F25:1 Ip A1:1 C444\n";
+    assert!(data.starts_with(header));
+
+    // Każda klatka to znacznik FRAME i trzy pełne płaszczyzny (próbkowanie 4:4:4)
+    let frame_size = b"FRAME\n".len() + 3 * pixels;
+    assert_eq!(data.len(), header.len() + frames.len() * frame_size);
+
+    for (frame, luma) in data[header.len()..].chunks(frame_size).zip([16, 235]) {
+        assert!(frame.starts_with(b"FRAME\n"));
+
+        let (y_plane, chroma_planes) = frame[6..].split_at(pixels);
+        assert_eq!(y_plane, vec![luma; pixels].as_slice());
+        assert_eq!(chroma_planes, vec![128; 2 * pixels].as_slice());
+    }
+}
+
+#[test]
+fn png_sequence_numbers_frames() {
+    let config = encoder_configuration("png_sequence", "frames", (5, 3), 3);
+    assert_eq!(
+        config.resolved_output_format(),
+        Some(OutputFormat::PngSequence)
+    );
+
+    let frames = test_frames(&config);
+    encode(&config, &frames);
+
+    let mut names: Vec<String> = std::fs::read_dir(&config.output_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_str().unwrap().to_string())
+        .collect();
+    names.sort();
+    assert_eq!(
+        names,
+        ["frame_00001.png", "frame_00002.png", "frame_00003.png"]
+    );
+
+    for (name, frame) in names.iter().zip(&frames) {
+        let image = image::open(std::path::Path::new(&config.output_path).join(name)).unwrap();
+        assert_eq!(image.to_rgb8().into_raw(), *frame);
+    }
+
+    // Liczba cyfr numeru klatki wystarcza dla wszystkich klatek animacji
+    let config = encoder_configuration("png_sequence_digits", "frames", (5, 3), 123_456);
+    encode(&config, &test_frames(&config)[..1]);
+    assert!(std::path::Path::new(&config.output_path)
+        .join("frame_000001.png")
+        .exists());
+}
+
+/// Konfiguracja zewnętrznego kodera wykonującego podany skrypt powłoki (ścieżka pliku
+/// wynikowego jest przekazywana jako pierwszy argument skryptu).
+#[cfg(unix)]
+fn pipe_configuration(name: &str, script: &str) -> Configuration {
+    let mut config = encoder_configuration(name, "output.mp4", (5, 3), 2);
+    config.frame_rate = 12;
+    config.output_command = ["sh", "-c", script, "sh", "{output}"]
+        .iter()
+        .map(|argument| argument.to_string())
+        .collect();
+
+    config
+}
+
+#[cfg(unix)]
+#[test]
+fn pipe_receives_frames_and_parameters() {
+    let config = pipe_configuration(
+        "pipe",
+        r#"echo {width} {height} {frame_rate} > "$1.txt"; cat > "$1""#,
+    );
+    assert_eq!(config.resolved_output_format(), Some(OutputFormat::Pipe));
+
+    let frames = test_frames(&config);
+    encode(&config, &frames);
+
+    assert_eq!(std::fs::read(&config.output_path).unwrap(), frames.concat());
+    assert_eq!(
+        std::fs::read_to_string(format!("{}.txt", config.output_path)).unwrap(),
+        "5 3 12\n"
+    );
+}
+
+/// Błąd zapisu klatek (lub zakończenia zapisu) zewnętrznym koderem.
+fn pipe_error(config: &Configuration) -> ForestFireError {
+    let mut encoder = match create_encoder(config) {
+        Ok(encoder) => encoder,
+        Err(error) => return error,
+    };
+
+    for frame in test_frames(config) {
+        if let Err(error) = encoder.encode_frame(&frame) {
+            return error;
+        }
+    }
+
+    encoder.finish().unwrap_err()
+}
+
+#[test]
+fn pipe_reports_missing_program() {
+    let mut config = encoder_configuration("pipe_missing", "output.mp4", (5, 3), 2);
+    config.output_command = vec!["forest_fire_missing_encoder".to_string()];
+
+    match pipe_error(&config) {
+        ForestFireError::Io {
+            operation, path, ..
+        } => {
+            assert_eq!(operation, Operation::StartEncoder);
+            assert_eq!(path, "forest_fire_missing_encoder");
+        }
+        error => panic!("{:?}", error),
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn pipe_reports_encoder_failure() {
+    // Koder kończący działanie z błędem po odczytaniu klatek oraz koder, który nie
+    // odczytuje klatek
+    for script in [
+        "cat > /dev/null; echo encoder failure >&2; exit 3",
+        "echo encoder failure >&2; exit 3",
+    ] {
+        match pipe_error(&pipe_configuration("pipe_failure", script)) {
+            ForestFireError::Encoding {
+                operation, message, ..
+            } => {
+                assert_eq!(operation, Operation::RunEncoder);
+                assert!(message.contains("encoder failure"), "{}", message);
+            }
+            error => panic!("{:?}", error),
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Testy dziedziczenia konfiguracji (`extends`) i wbudowanych presetów.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use forest_fire::{
+    configuration::{parse_configuration, validate_configuration, Configuration},
+    error::ForestFireError,
+    presets::PRESETS,
+};
+
+// Testy dziedziczenia z plików wymagają cechy `fs`
+#[cfg(feature = "fs")]
+use common::{as_value, directory};
+#[cfg(feature = "fs")]
+use forest_fire::configuration::{
+    Asset, FirefightingStrategy, ManagementAction, ManagementStrategy, OutputFormat,
+};
+
+/// Opis błędu parsowania (lub panika dla innych wyników).
+fn parse_error(result: Result<Configuration, ForestFireError>) -> String {
+    match result {
+        Err(ForestFireError::Parse { message, .. }) => message,
+        result => panic!("{:?}", result.map(|_| ())),
+    }
+}
+
+#[test]
+fn every_preset_is_valid() {
+    for (name, _) in PRESETS {
+        let config = parse_configuration(&format!("(extends: \"{}\")", name)).unwrap();
+
+        validate_configuration(&config).unwrap_or_else(|error| panic!("{}: {}", name, error));
+        assert_eq!(config.output_path, format!("{}.gif", name));
+    }
+}
+
+#[test]
+fn explicit_parameters_replace_inherited_ones() {
+    let base = parse_configuration("(extends: \"aquamarine\")").unwrap();
+    let config = parse_configuration(
+        "(extends: \"aquamarine\", frames: 7, forest_color_palette: [(1, 2, 3)], seed: 5)",
+    )
+    .unwrap();
+
+    assert_eq!(config.frames, 7);
+    assert_eq!(config.seed, Some(5));
+    // Listy są zastępowane w całości, a nie łączone
+    assert_eq!(config.forest_color_palette, vec![(1, 2, 3)]);
+    assert_eq!(config.cell_size, base.cell_size);
+    assert_eq!(config.inflammability, base.inflammability);
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn every_parameter_can_be_overridden() {
+    // Literał bez `..`: dodanie parametru do `Configuration` wymaga uzupełnienia tego testu,
+    // a parametr pominięty w warstwie konfiguracji przyjąłby wartość odziedziczoną
+    let config = Configuration {
+        version: None,
+        frames: 11,
+        frame_rate: 12,
+        steps_per_frame: 3,
+        warmup_steps: 14,
+        output_path: "other.png".to_string(),
+        output_format: Some(OutputFormat::Apng),
+        output_command: vec!["encoder".to_string()],
+        gif_quality: 15,
+        resolution: (512, 256),
+        cell_size: 8,
+        seed: Some(16),
+        alive_fraction: 0.17,
+        sprout_probability: 0.18,
+        random_sprout_probability: 0.19,
+        growth_rate: 0.2,
+        inflammability: 0.21,
+        self_ignition_probability: 0.22,
+        burning_rate: 0.23,
+        mortality_age: 24,
+        mortality_probability: 0.25,
+        mortality_exponent: 2.6,
+        snag_inflammability: 0.27,
+        snag_decay_rate: 0.28,
+        firefighter_count: 29,
+        firefighter_strategy: FirefightingStrategy::ProtectArea { x: 30, y: 31 },
+        firefighter_speed: 32,
+        firefighter_radius: 33,
+        firefighter_wetting_steps: 55,
+        assets: vec![Asset {
+            name: "village".to_string(),
+            color: (34, 35, 36),
+            rectangles: vec![(1, 2, 3, 4)],
+            mask_path: None,
+        }],
+        management_interval: 37,
+        management_action: ManagementAction::ClearCut,
+        management_strategy: ManagementStrategy::OldestFirst,
+        management_fraction: 0.38,
+        management_patch_size: 39,
+        management_regions: vec![(5, 6, 7, 8)],
+        management_baseline: false,
+        forest_color_palette: vec![(40, 41, 42)],
+        fire_color: (43, 44, 45),
+        ground_color: (46, 47, 48),
+        snag_color: (49, 50, 51),
+        firefighter_color: (52, 53, 54),
+    };
+
+    let mut value = as_value(&config);
+    value["extends"] = "green_tea".into();
+
+    let path = directory("override").join("child.json");
+    std::fs::write(&path, value.to_string()).unwrap();
+
+    let parsed = parse_configuration(&format!("(extends: {:?})", path.to_str().unwrap())).unwrap();
+
+    assert_eq!(as_value(&parsed), as_value(&config));
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn relative_paths_are_resolved_against_the_including_file() {
+    let directory = directory("relative");
+    std::fs::create_dir_all(directory.join("base")).unwrap();
+    std::fs::write(
+        directory.join("base/base.ron"),
+        "(extends: \"critical\", frames: 21)",
+    )
+    .unwrap();
+    std::fs::write(
+        directory.join("child.ron"),
+        "(extends: \"base/base.ron\", frame_rate: 10)",
+    )
+    .unwrap();
+
+    let path = directory.join("child.ron");
+    let config = parse_configuration(&format!("(extends: {:?})", path.to_str().unwrap())).unwrap();
+
+    assert_eq!((config.frames, config.frame_rate), (21, 10));
+    assert_eq!(config.output_path, "critical.gif");
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn cyclic_inheritance_is_reported() {
+    let directory = directory("cycle");
+    std::fs::write(directory.join("a.ron"), "(extends: \"b.ron\")").unwrap();
+    std::fs::write(directory.join("b.ron"), "(extends: \"./a.ron\")").unwrap();
+
+    let path = directory.join("a.ron");
+    let message = parse_error(parse_configuration(&format!(
+        "(extends: {:?})",
+        path.to_str().unwrap()
+    )));
+
+    assert!(message.contains("a.ron -> "), "{}", message);
+    assert!(message.ends_with("./a.ron"), "{}", message);
+}
+
+#[cfg(not(feature = "fs"))]
+#[test]
+fn files_are_not_read_without_fs_feature() {
+    match parse_configuration("(extends: \"base.ron\")") {
+        Err(ForestFireError::Io { path, .. }) => assert_eq!(path, "base.ron"),
+        result => panic!("{:?}", result.map(|_| ())),
+    }
+}
+
+#[test]
+fn unknown_preset_is_reported() {
+    let message = parse_error(parse_configuration("(extends: \"green_coffee\")"));
+
+    assert!(message.contains("green_coffee"), "{}", message);
+    assert!(message.contains("green_tea"), "{}", message);
+}
@@ -0,0 +1,72 @@
+"""Testy modułu rozszerzenia Pythona: `maturin develop` i `pytest tests/python`."""
+
+import numpy as np
+import pytest
+
+import forest_fire
+
+CONFIG = """(
+    frames: 10,
+    frame_rate: 25,
+    output_path: "output.gif",
+    resolution: (256, 256),
+    cell_size: 4,
+    seed: 1,
+)"""
+
+
+@pytest.fixture
+def simulation():
+    return forest_fire.Simulation(forest_fire.Configuration(CONFIG))
+
+
+def test_arrays_have_board_and_frame_shapes(simulation):
+    assert simulation.state.shape == (64, 64)
+    assert simulation.state.dtype == np.uint8
+    assert simulation.size.shape == (64, 64)
+    assert simulation.progress.shape == (64, 64)
+    assert simulation.frame.shape == (256, 256, 3)
+
+
+def test_step_updates_snapshot_in_place(simulation):
+    state = simulation.state
+
+    simulation.step(10)
+
+    assert simulation.statistics()["steps"] == 10
+    assert (state == simulation.state).all()
+
+    counts = np.bincount(state.ravel(), minlength=4)
+    assert counts[1] == simulation.statistics()["alive_cells"]
+    assert counts[2] == simulation.statistics()["on_fire_cells"]
+
+
+def test_ignite_and_clear_update_snapshot(simulation):
+    y, x = np.argwhere(simulation.state == 1)[0]
+
+    simulation.ignite(int(x), int(y))
+    assert simulation.state[y, x] == 2
+    assert simulation.progress[y, x] > 0
+
+    simulation.clear(int(x), int(y))
+    assert simulation.state[y, x] == 0
+
+
+def test_arrays_are_read_only(simulation):
+    with pytest.raises(ValueError):
+        simulation.state[0, 0] = 0
+
+
+def test_configuration_items():
+    config = forest_fire.Configuration(CONFIG)
+    config["inflammability"] = 0.25
+
+    assert config["inflammability"] == 0.25
+    assert config.to_dict()["frames"] == 10
+
+    with pytest.raises(KeyError):
+        config["missing"]
+
+    config["frames"] = 0
+    with pytest.raises(ValueError):
+        config.validate()
@@ -0,0 +1,105 @@
+//! Testy serwera podglądu symulacji (połączenia przez interfejs pętli zwrotnej): ostatnia
+//! klatka, statystyki i strumień MJPEG kończący się po ostatniej klatce symulacji.
+
+#![cfg(feature = "cli")]
+
+mod common;
+
+use common::valid_configuration;
+
+use forest_fire::server::serve_listener;
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+/// Uruchomienie serwera (w osobnym wątku) na losowym porcie interfejsu pętli zwrotnej.
+fn start_server() -> SocketAddr {
+    let mut config = valid_configuration();
+    config.frames = 3;
+    config.frame_rate = 50;
+    config.seed = Some(1);
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    std::thread::spawn(move || serve_listener(&config, listener).unwrap());
+
+    address
+}
+
+/// Żądanie GET: nagłówki i treść odpowiedzi.
+fn get(address: SocketAddr, path: &str) -> (String, Vec<u8>) {
+    let mut stream = TcpStream::connect(address).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(10)))
+        .unwrap();
+    write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).unwrap();
+
+    let split = response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .unwrap();
+
+    (
+        String::from_utf8(response[..split].to_vec()).unwrap(),
+        response[split + 4..].to_vec(),
+    )
+}
+
+/// Statystyki po zakończeniu symulacji.
+fn finished_stats(address: SocketAddr) -> serde_json::Value {
+    let start = Instant::now();
+
+    loop {
+        let (headers, body) = get(address, "/stats");
+        assert!(headers.starts_with("HTTP/1.1 200 OK"), "{}", headers);
+        assert!(headers.contains("application/json"), "{}", headers);
+
+        let stats: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        if stats["finished"] == true {
+            return stats;
+        }
+
+        assert!(start.elapsed() < Duration::from_secs(30), "{}", stats);
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
+
+#[test]
+fn frame_and_stats_are_served() {
+    let address = start_server();
+
+    let stats = finished_stats(address);
+    assert_eq!(stats["frame"], 3);
+    assert_eq!(stats["frames"], 3);
+    assert_eq!(stats["seed"], 1);
+    assert!(stats["alive_cells"].as_f64().unwrap() > 0.0, "{}", stats);
+
+    let (headers, body) = get(address, "/frame.jpg");
+    assert!(headers.contains("image/jpeg"), "{}", headers);
+
+    let frame = image::load_from_memory(&body).unwrap();
+    assert_eq!((frame.width(), frame.height()), (256, 256));
+
+    let (headers, _) = get(address, "/missing");
+    assert!(headers.starts_with("HTTP/1.1 404"), "{}", headers);
+}
+
+#[test]
+fn stream_ends_after_last_frame() {
+    let address = start_server();
+    finished_stats(address);
+
+    // Po zakończeniu symulacji strumień zawiera ostatnią klatkę i znacznik końca
+    let (headers, body) = get(address, "/stream");
+    assert!(headers.contains("multipart/x-mixed-replace"), "{}", headers);
+
+    let body = String::from_utf8_lossy(&body);
+    assert_eq!(body.matches("Content-Type: image/jpeg").count(), 1);
+    assert!(body.ends_with("--frame--\r\n"));
+}
@@ -0,0 +1,49 @@
+//! Testy modelu: początkowy wiek drzew nie przekracza wieku referencyjnego śmiertelności,
+//! również przy zerowym tempie wzrostu.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use common::valid_configuration;
+
+use forest_fire::simulation::{initialize_grid, Cell, Simulation};
+
+use rand::{rngs::StdRng, SeedableRng};
+
+/// Liczba żywych drzew na planszy.
+fn alive_cells(state: &[Cell]) -> usize {
+    state
+        .iter()
+        .filter(|cell| matches!(cell, Cell::Alive { .. }))
+        .count()
+}
+
+#[test]
+fn initial_forest_survives_zero_growth_rate() {
+    let mut config = valid_configuration();
+    config.frames = 1;
+    config.seed = Some(1);
+    config.growth_rate = 0.0;
+    config.mortality_age = 1500;
+    config.mortality_probability = 0.01;
+    config.self_ignition_probability = 0.0;
+
+    let grid = initialize_grid(&mut StdRng::seed_from_u64(1), &config);
+    assert!(grid.iter().all(|cell| match cell {
+        Cell::Alive { age, .. } => *age <= config.mortality_age,
+        _ => true,
+    }));
+
+    let mut simulation = Simulation::new(&config).unwrap();
+    let initial = alive_cells(simulation.state());
+    simulation.step();
+
+    // Drzewa w wieku referencyjnym obumierają z prawdopodobieństwem `mortality_probability`
+    assert!(
+        alive_cells(simulation.state()) * 10 > initial * 9,
+        "{} -> {}",
+        initial,
+        alive_cells(simulation.state())
+    );
+}
@@ -0,0 +1,195 @@
+//! Testy specyfikacji konfiguracji: parametry wymagane, wartości domyślne i zakresy
+//! generowane na podstawie struktury `Configuration` są zgodne z deserializacją i walidacją,
+//! a zapisany schemat JSON Schema jest aktualny.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use common::as_value;
+
+use forest_fire::{
+    configuration::{validate_configuration, Configuration},
+    error::ForestFireError,
+    specification::{json_schema, parameters, Parameter},
+};
+
+use serde_json::Value;
+
+/// Poprawna konfiguracja w postaci wartości JSON.
+fn valid_configuration() -> Value {
+    as_value(&common::valid_configuration())
+}
+
+/// Nazwy parametrów, dla których konfiguracja narusza reguły walidacji.
+fn violated_fields(value: &Value) -> Vec<String> {
+    let config: Configuration = serde_json::from_value(value.clone()).unwrap();
+
+    match validate_configuration(&config) {
+        Ok(()) => Vec::new(),
+        Err(ForestFireError::Validation { violations }) => violations
+            .into_iter()
+            .map(|violation| violation.field)
+            .collect(),
+        Err(error) => panic!("nieoczekiwany błąd: {}", error),
+    }
+}
+
+/// Wartości parametru (lub elementu krotki) na granicach zakresów oraz tuż poza nimi:
+/// (wartość, czy poprawna).
+fn boundary_values(parameter: &Parameter, index: usize) -> Vec<(f64, bool)> {
+    let bounds = parameter.definition.bounds[index];
+    let step = if parameter.definition.type_name.contains("f32") {
+        0.01
+    } else {
+        1.0
+    };
+
+    let mut values = if bounds.exclusive_minimum {
+        vec![(bounds.minimum, false), (bounds.minimum + step, true)]
+    } else {
+        vec![(bounds.minimum - step, false), (bounds.minimum, true)]
+    };
+
+    if let Some(maximum) = bounds.maximum {
+        values.extend([(maximum, true), (maximum + step, false)]);
+    }
+
+    // Wartości ujemne nie mieszczą się w typach bez znaku
+    values.retain(|(value, _)| step < 1.0 || *value >= 0.0);
+    values
+}
+
+#[test]
+fn required_parameters_have_no_default() {
+    let required: Vec<&str> = parameters()
+        .iter()
+        .filter(|parameter| parameter.required())
+        .map(|parameter| parameter.definition.name)
+        .collect();
+
+    assert_eq!(
+        required,
+        [
+            "frames",
+            "frame_rate",
+            "output_path",
+            "resolution",
+            "cell_size"
+        ]
+    );
+}
+
+#[test]
+fn defaults_match_omitted_parameters() {
+    let config = valid_configuration();
+
+    for parameter in parameters() {
+        if let Some(default) = &parameter.default {
+            assert_eq!(
+                *default, config[parameter.definition.name],
+                "{}",
+                parameter.definition.name
+            );
+        }
+    }
+}
+
+#[test]
+fn bounds_match_validation() {
+    for parameter in parameters() {
+        let name = parameter.definition.name;
+
+        for index in 0..parameter.definition.bounds.len() {
+            for (value, valid) in boundary_values(&parameter, index) {
+                // Zakresy nie obejmują ograniczeń zależnych od formatu pliku wynikowego
+                // (`frame_rate` dla animacji GIF)
+                let mut config = valid_configuration();
+                config["output_format"] = "Apng".into();
+
+                let field = match config[name].as_array_mut() {
+                    Some(elements) => &mut elements[index],
+                    None => &mut config[name],
+                };
+
+                *field = if field.is_u64() {
+                    Value::from(value as u64)
+                } else {
+                    Value::from(value)
+                };
+
+                assert_eq!(
+                    !violated_fields(&config).contains(&name.to_string()),
+                    valid,
+                    "{}[{}] = {}",
+                    name,
+                    index,
+                    value
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn enumerated_values_are_accepted() {
+    /// Wartości wyliczeń (`enum`) występujące w schemacie parametru.
+    fn enumerated(schema: &Value, values: &mut Vec<Value>) {
+        match schema {
+            Value::Object(object) => {
+                if let Some(Value::Array(variants)) = object.get("enum") {
+                    values.extend(variants.iter().cloned());
+                }
+
+                object.values().for_each(|value| enumerated(value, values));
+            }
+            Value::Array(array) => array.iter().for_each(|value| enumerated(value, values)),
+            _ => {}
+        }
+    }
+
+    let schema = json_schema();
+
+    for (name, property) in schema["properties"].as_object().unwrap() {
+        if name == "extends" {
+            continue;
+        }
+
+        let mut values = Vec::new();
+        enumerated(property, &mut values);
+
+        for value in values {
+            let mut config = valid_configuration();
+            config[name] = value.clone();
+
+            serde_json::from_value::<Configuration>(config)
+                .unwrap_or_else(|error| panic!("{} = {}: {}", name, value, error));
+        }
+    }
+}
+
+#[test]
+fn schema_describes_every_parameter() {
+    let schema = json_schema();
+    let properties = schema["properties"].as_object().unwrap();
+    let config = valid_configuration();
+
+    for name in config.as_object().unwrap().keys() {
+        assert!(properties.contains_key(name), "{}", name);
+    }
+
+    // Parametry z wartością `None` pomijaną przy zapisie (`version`) i parametr `extends`
+    assert_eq!(properties.len(), parameters().len() + 1);
+}
+
+#[test]
+fn schema_file_is_up_to_date() {
+    let schema = serde_json::to_string_pretty(&json_schema()).unwrap() + "\n";
+
+    assert_eq!(
+        schema,
+        include_str!("../config/configuration.schema.json"),
+        "schemat należy wygenerować ponownie: forest_fire --lang en print-spec --schema \
+            config/configuration.schema.json"
+    );
+}
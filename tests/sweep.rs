@@ -0,0 +1,208 @@
+//! Testy przeglądu parametrów: wartości z zakresów są zaokrąglane wyłącznie dla parametrów
+//! całkowitoliczbowych, tabela podsumowująca jest zapisywana tylko wtedy, gdy wszystkie
+//! przebiegi zakończyły się powodzeniem, a każdy jej wiersz można powtórzyć na podstawie
+//! konfiguracji bazowej, ziarna i wartości parametrów z tego wiersza.
+
+#![cfg(feature = "cli")]
+
+mod common;
+
+use common::{directory, small_configuration};
+
+use forest_fire::{
+    configuration::override_configuration,
+    error::{ForestFireError, Operation},
+    simulation::simulate_without_output,
+    statistics::SUMMARY_COLUMNS,
+    sweep::sweep,
+};
+
+use std::path::{Path, PathBuf};
+
+/// Ścieżka tabeli podsumowującej w katalogu tymczasowym testu.
+fn summary_path(name: &str) -> PathBuf {
+    directory(&format!("sweep_{}", name)).join("summary.csv")
+}
+
+/// Kolumna tabeli podsumowującej o podanej nazwie (bez nagłówka).
+fn column(path: &Path, name: &str) -> Vec<String> {
+    let table = std::fs::read_to_string(path).unwrap();
+    let mut lines = table.lines();
+    let index = lines
+        .next()
+        .unwrap()
+        .split(',')
+        .position(|header| header == name)
+        .unwrap();
+
+    lines
+        .map(|line| line.split(',').nth(index).unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn fractional_parameters_are_not_rounded() {
+    let path = summary_path("fractional");
+    let config = small_configuration();
+
+    sweep(
+        &config,
+        &["inflammability=0:1:5".to_string()],
+        None,
+        1,
+        path.to_str().unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        column(&path, "inflammability"),
+        ["0.0", "0.25", "0.5", "0.75", "1.0"]
+    );
+
+    sweep(
+        &config,
+        &["inflammability=0:1".to_string()],
+        Some(4),
+        1,
+        path.to_str().unwrap(),
+    )
+    .unwrap();
+
+    let samples = column(&path, "inflammability");
+    assert_eq!(samples.len(), 4);
+    assert!(
+        samples
+            .iter()
+            .all(|value| value.parse::<f64>().unwrap().fract() != 0.0),
+        "{:?}",
+        samples
+    );
+}
+
+#[test]
+fn integer_parameters_are_rounded() {
+    let path = summary_path("integer");
+
+    sweep(
+        &small_configuration(),
+        &["warmup_steps=0:5:3".to_string()],
+        None,
+        1,
+        path.to_str().unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(column(&path, "warmup_steps"), ["0", "3", "5"]);
+}
+
+#[test]
+fn empty_sweeps_are_rejected() {
+    let path = summary_path("empty");
+    let parameter = ["inflammability=0.1:0.3".to_string()];
+
+    for (specifications, samples, field) in [
+        (&parameter[..], Some(0), "samples"),
+        (&[], None, "param"),
+        (&[], Some(4), "param"),
+    ] {
+        let result = sweep(
+            &small_configuration(),
+            specifications,
+            samples,
+            1,
+            path.to_str().unwrap(),
+        );
+
+        match result {
+            Err(ForestFireError::Validation { violations }) => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].field, field);
+            }
+            result => panic!("{:?}", result),
+        }
+    }
+
+    assert!(!path.exists());
+}
+
+#[test]
+fn failed_run_leaves_no_partial_table() {
+    let path = summary_path("failed");
+    std::fs::write(&path, "previous table\n").unwrap();
+
+    let result = sweep(
+        &small_configuration(),
+        &[
+            r#"assets=[[], [{"name": "village", "color": [1, 2, 3], "mask_path": "missing.png"}]]"#
+                .to_string(),
+        ],
+        None,
+        1,
+        path.to_str().unwrap(),
+    );
+
+    match result {
+        Err(ForestFireError::Io { operation, .. }) => {
+            assert_eq!(operation, Operation::LoadAssetMask)
+        }
+        result => panic!("{:?}", result),
+    }
+
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "previous table\n");
+}
+
+#[test]
+fn rows_are_reproducible_from_seed_and_values() {
+    let path = summary_path("reproducible");
+    let config = override_configuration(
+        &small_configuration(),
+        &[
+            "frames=30".to_string(),
+            "self_ignition_probability=0.001".to_string(),
+        ],
+    )
+    .unwrap();
+
+    sweep(
+        &config,
+        &[
+            "inflammability=0.1:0.3".to_string(),
+            "growth_rate=[0.001, 0.01]".to_string(),
+        ],
+        None,
+        2,
+        path.to_str().unwrap(),
+    )
+    .unwrap();
+
+    let seeds = column(&path, "seed");
+    let inflammability = column(&path, "inflammability");
+    let growth_rate = column(&path, "growth_rate");
+    assert!(column(&path, "ignitions").iter().any(|value| value != "0"));
+
+    let summaries: Vec<Vec<String>> = SUMMARY_COLUMNS
+        .iter()
+        .map(|name| column(&path, name))
+        .collect();
+
+    for row in 0..seeds.len() {
+        let run_config = override_configuration(
+            &config,
+            &[
+                format!("seed={}", seeds[row]),
+                format!("inflammability={}", inflammability[row]),
+                format!("growth_rate={}", growth_rate[row]),
+            ],
+        )
+        .unwrap();
+
+        let summary = simulate_without_output(&run_config, "")
+            .unwrap()
+            .summary()
+            .map(|value| value.to_string());
+
+        for (column, value) in summaries.iter().zip(summary) {
+            assert_eq!(column[row], value, "wiersz {}", row);
+        }
+    }
+}
@@ -0,0 +1,380 @@
+//! Testy walidacji konfiguracji: każda reguła jest sprawdzana osobno (nazwa parametru,
+//! ograniczenie i podana wartość), a błędy wielu parametrów są zgłaszane jednocześnie.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use common::valid_configuration;
+
+use forest_fire::{
+    configuration::{
+        parse_configuration, validate_configuration, Asset, Configuration, FirefightingStrategy,
+        OutputFormat,
+    },
+    error::{ForestFireError, Violation},
+};
+
+/// Dostęp do parametru konfiguracji typu `f32`.
+type Field = fn(&mut Configuration) -> &mut f32;
+
+/// Naruszone reguły walidacji.
+fn violations(config: &Configuration) -> Vec<Violation> {
+    match validate_configuration(config) {
+        Ok(()) => Vec::new(),
+        Err(ForestFireError::Validation { violations }) => violations,
+        Err(error) => panic!("nieoczekiwany błąd: {}", error),
+    }
+}
+
+/// Sprawdzenie, że konfiguracja narusza dokładnie jedną regułę dla parametru `field`.
+fn assert_single_violation(config: &Configuration, field: &str, value: &str, constraint: &str) {
+    assert_eq!(
+        violations(config),
+        vec![Violation::new(field, value, constraint)]
+    );
+}
+
+#[test]
+fn valid_configuration_passes() {
+    assert!(validate_configuration(&valid_configuration()).is_ok());
+}
+
+#[test]
+fn sample_configurations_pass() {
+    for path in ["config/green_tea.ron", "config/aquamarine.ron"] {
+        let text = std::fs::read_to_string(path).unwrap();
+        let config = parse_configuration(&text).unwrap();
+
+        assert_eq!(violations(&config), Vec::new(), "{}", path);
+    }
+}
+
+#[test]
+fn frames_must_be_positive() {
+    let mut config = valid_configuration();
+    config.frames = 0;
+
+    assert_single_violation(&config, "frames", "0", "greater than 0");
+}
+
+#[test]
+fn frame_rate_must_be_in_range() {
+    for frame_rate in [0, 101] {
+        let mut config = valid_configuration();
+        config.frame_rate = frame_rate;
+
+        assert_single_violation(
+            &config,
+            "frame_rate",
+            &frame_rate.to_string(),
+            "in the range 1..100",
+        );
+    }
+}
+
+#[test]
+fn gif_frame_rate_is_limited() {
+    let mut config = valid_configuration();
+    config.frame_rate = 50;
+    assert!(validate_configuration(&config).is_ok());
+
+    config.frame_rate = 51;
+    assert_single_violation(&config, "frame_rate", "51", "at most 50 for GIF animations");
+
+    // Pozostałe formaty obsługują pełny zakres
+    config.frame_rate = 100;
+    config.output_path = "output.png".to_string();
+    assert!(validate_configuration(&config).is_ok());
+}
+
+#[test]
+fn steps_per_frame_must_be_positive() {
+    let mut config = valid_configuration();
+    config.steps_per_frame = 0;
+
+    assert_single_violation(&config, "steps_per_frame", "0", "greater than 0");
+}
+
+#[test]
+fn output_format_must_be_known() {
+    let mut config = valid_configuration();
+    config.output_path = "output.avi".to_string();
+
+    let violations = violations(&config);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].field, "output_format");
+    assert_eq!(violations[0].value, "None (output_path: \"output.avi\")");
+
+    config.output_format = Some(OutputFormat::Gif);
+    assert!(validate_configuration(&config).is_ok());
+}
+
+#[test]
+fn gif_quality_must_be_in_range() {
+    for gif_quality in [0, 101] {
+        let mut config = valid_configuration();
+        config.gif_quality = gif_quality;
+
+        assert_single_violation(
+            &config,
+            "gif_quality",
+            &gif_quality.to_string(),
+            "in the range 1..100",
+        );
+    }
+}
+
+#[test]
+fn resolution_must_be_in_range() {
+    for resolution in [(128, 256), (4352, 256), (256, 128), (256, 2304)] {
+        let mut config = valid_configuration();
+        config.resolution = resolution;
+
+        assert_single_violation(
+            &config,
+            "resolution",
+            &format!("{:?}", resolution),
+            "in the range (256..4096, 256..2160)",
+        );
+    }
+}
+
+#[test]
+fn cell_size_must_divide_resolution() {
+    let mut config = valid_configuration();
+    config.cell_size = 5;
+
+    assert_single_violation(
+        &config,
+        "cell_size",
+        "5 (256 % 5 = 1, 256 % 5 = 1)",
+        "a divisor of both the horizontal and the vertical resolution",
+    );
+
+    config.cell_size = 0;
+
+    assert_single_violation(&config, "cell_size", "0", "greater than 0");
+}
+
+#[test]
+fn probabilities_must_be_in_unit_range() {
+    let fields: [(&str, Field); 8] = [
+        ("alive_fraction", |config| &mut config.alive_fraction),
+        ("sprout_probability", |config| {
+            &mut config.sprout_probability
+        }),
+        ("random_sprout_probability", |config| {
+            &mut config.random_sprout_probability
+        }),
+        ("inflammability", |config| &mut config.inflammability),
+        ("self_ignition_probability", |config| {
+            &mut config.self_ignition_probability
+        }),
+        ("mortality_probability", |config| {
+            &mut config.mortality_probability
+        }),
+        ("snag_inflammability", |config| {
+            &mut config.snag_inflammability
+        }),
+        ("management_fraction", |config| {
+            &mut config.management_fraction
+        }),
+    ];
+
+    for (field, value) in fields {
+        for invalid in [-0.1, 1.5] {
+            let mut config = valid_configuration();
+            *value(&mut config) = invalid;
+
+            assert_single_violation(
+                &config,
+                field,
+                &format!("{:?}", invalid),
+                "in the range 0.0..1.0",
+            );
+        }
+    }
+}
+
+#[test]
+fn non_finite_values_are_out_of_range() {
+    let mut config = valid_configuration();
+    config.inflammability = f32::NAN;
+
+    assert_single_violation(&config, "inflammability", "NaN", "in the range 0.0..1.0");
+}
+
+#[test]
+fn growth_rate_must_not_be_negative() {
+    let mut config = valid_configuration();
+    config.growth_rate = -0.5;
+
+    assert_single_violation(
+        &config,
+        "growth_rate",
+        "-0.5",
+        "greater than or equal to 0.0",
+    );
+}
+
+#[test]
+fn burning_rate_must_be_positive() {
+    let mut config = valid_configuration();
+    config.burning_rate = 0.0;
+
+    assert_single_violation(&config, "burning_rate", "0.0", "greater than 0.0");
+}
+
+#[test]
+fn mortality_age_must_be_positive() {
+    let mut config = valid_configuration();
+    config.mortality_age = 0;
+
+    assert_single_violation(&config, "mortality_age", "0", "greater than 0");
+}
+
+#[test]
+fn mortality_exponent_must_not_be_negative() {
+    let mut config = valid_configuration();
+    config.mortality_exponent = -1.0;
+
+    assert_single_violation(
+        &config,
+        "mortality_exponent",
+        "-1.0",
+        "greater than or equal to 0.0",
+    );
+}
+
+#[test]
+fn snag_decay_rate_must_be_positive() {
+    let mut config = valid_configuration();
+    config.snag_decay_rate = -0.25;
+
+    assert_single_violation(&config, "snag_decay_rate", "-0.25", "greater than 0.0");
+}
+
+#[test]
+fn firefighter_speed_must_be_positive() {
+    let mut config = valid_configuration();
+    config.firefighter_speed = 0;
+
+    assert_single_violation(&config, "firefighter_speed", "0", "greater than 0");
+}
+
+#[test]
+fn protected_area_must_lie_on_board() {
+    let mut config = valid_configuration();
+    config.firefighter_strategy = FirefightingStrategy::ProtectArea { x: 64, y: 10 };
+
+    assert_single_violation(
+        &config,
+        "firefighter_strategy",
+        "ProtectArea(x: 64, y: 10)",
+        "a protected point on the board (0..64, 0..64)",
+    );
+
+    config.firefighter_strategy = FirefightingStrategy::ProtectArea { x: 63, y: 63 };
+    assert!(validate_configuration(&config).is_ok());
+}
+
+#[test]
+fn assets_must_have_rectangles_or_mask() {
+    let mut config = valid_configuration();
+    config.assets = vec![Asset {
+        name: "village".to_string(),
+        color: (255, 0, 0),
+        rectangles: Vec::new(),
+        mask_path: None,
+    }];
+
+    assert_single_violation(
+        &config,
+        "assets[\"village\"]",
+        "no rectangles and no mask",
+        "rectangles or a mask",
+    );
+}
+
+#[test]
+fn asset_rectangles_must_lie_on_board() {
+    for rectangle in [(60, 0, 5, 1), (0, 0, 0, 1), (0, u32::MAX, 1, 1)] {
+        let mut config = valid_configuration();
+        config.assets = vec![Asset {
+            name: "village".to_string(),
+            color: (255, 0, 0),
+            rectangles: vec![(0, 0, 4, 4), rectangle],
+            mask_path: None,
+        }];
+
+        assert_single_violation(
+            &config,
+            "assets[\"village\"].rectangles",
+            &format!("{:?}", rectangle),
+            "non-empty rectangles on the board (64 x 64)",
+        );
+    }
+}
+
+#[test]
+fn management_patch_size_must_be_positive() {
+    let mut config = valid_configuration();
+    config.management_patch_size = 0;
+
+    assert_single_violation(&config, "management_patch_size", "0", "greater than 0");
+}
+
+#[test]
+fn management_regions_must_lie_on_board() {
+    let mut config = valid_configuration();
+    config.management_regions = vec![(0, 0, 64, 64), (32, 32, 33, 1)];
+
+    assert_single_violation(
+        &config,
+        "management_regions",
+        "(32, 32, 33, 1)",
+        "non-empty rectangles on the board (64 x 64)",
+    );
+}
+
+#[test]
+fn forest_color_palette_must_not_be_empty() {
+    let mut config = valid_configuration();
+    config.forest_color_palette = Vec::new();
+
+    assert_single_violation(
+        &config,
+        "forest_color_palette",
+        "[]",
+        "a non-empty list of colours",
+    );
+}
+
+#[test]
+fn all_violations_are_reported_together() {
+    let mut config = valid_configuration();
+    config.frames = 0;
+    config.random_sprout_probability = 2.0;
+    config.burning_rate = 0.0;
+    config.forest_color_palette = Vec::new();
+
+    let fields: Vec<String> = violations(&config)
+        .into_iter()
+        .map(|violation| violation.field)
+        .collect();
+
+    assert_eq!(
+        fields,
+        [
+            "frames",
+            "random_sprout_probability",
+            "burning_rate",
+            "forest_color_palette"
+        ]
+    );
+
+    let message = validate_configuration(&config).unwrap_err().to_string();
+    assert!(message.starts_with("Configuration error: parameter \"frames\""));
+    assert!(message.contains("; parameter \"random_sprout_probability\""));
+}
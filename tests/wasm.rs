@@ -0,0 +1,39 @@
+//! Testy interfejsu WebAssembly, uruchamiane w środowisku bez przeglądarki:
+//! `wasm-pack test --node --no-default-features --features wasm`.
+
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use forest_fire::wasm::WasmSimulation;
+
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const CONFIG: &str = r#"{
+    "frames": 10,
+    "frame_rate": 25,
+    "resolution": [256, 256],
+    "cell_size": 4,
+    "seed": 1
+}"#;
+
+#[wasm_bindgen_test]
+fn simulation_steps_and_draws_rgba_frames() {
+    let mut simulation = WasmSimulation::new(CONFIG).unwrap();
+
+    assert_eq!((simulation.width(), simulation.height()), (256, 256));
+    assert_eq!(simulation.frame_len(), 256 * 256 * 4);
+
+    for _ in 0..10 {
+        simulation.step();
+    }
+
+    let frame = unsafe { std::slice::from_raw_parts(simulation.frame(), simulation.frame_len()) };
+    assert!(frame.chunks_exact(4).all(|pixel| pixel[3] == 255));
+
+    let statistics: serde_json::Value = serde_json::from_str(&simulation.statistics()).unwrap();
+    assert_eq!(statistics["steps"], 10.0);
+}
+
+#[wasm_bindgen_test]
+fn invalid_configuration_is_rejected() {
+    assert!(WasmSimulation::new(r#"{ "frames": 10 }"#).is_err());
+}